@@ -0,0 +1,60 @@
+//! Compares the collecting input helpers with their lazy and byte-based counterparts.
+//! Run with `cargo bench --bench input`.
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use advent_of_code::utils::bytes;
+use advent_of_code::utils::input::{
+    parse_lines, parse_lines_iter, split_parse_lines, split_parse_lines_iter,
+};
+
+const BUDGET: Duration = Duration::from_millis(500);
+
+fn main() {
+    // inputs shaped like the ones of 2015 day 2 and day 24.
+    let boxes: String = (0..10_000u64)
+        .map(|i| format!("{}x{}x{}\n", i % 29 + 1, i % 17 + 1, i % 31 + 1))
+        .collect();
+    let numbers: String = (0..10_000u64).map(|i| format!("{}\n", i * 7919)).collect();
+
+    println!("{} lines of `LxWxH`:", boxes.lines().count());
+    bench("split_parse_lines", || {
+        split_parse_lines::<u64>(black_box(&boxes), 'x')
+            .iter()
+            .flatten()
+            .sum::<u64>()
+    });
+    bench("split_parse_lines_iter", || {
+        split_parse_lines_iter::<u64>(black_box(&boxes), 'x')
+            .flatten()
+            .sum::<u64>()
+    });
+    bench("bytes::integers", || {
+        bytes::integers::<u64>(black_box(boxes.as_bytes())).sum::<u64>()
+    });
+
+    println!("{} lines of one integer:", numbers.lines().count());
+    bench("parse_lines", || {
+        parse_lines::<u64>(black_box(&numbers)).iter().sum::<u64>()
+    });
+    bench("parse_lines_iter", || {
+        parse_lines_iter::<u64>(black_box(&numbers)).sum::<u64>()
+    });
+    bench("bytes::integers", || {
+        bytes::integers::<u64>(black_box(numbers.as_bytes())).sum::<u64>()
+    });
+}
+
+/// Runs `f` for about [`BUDGET`] and prints the average time per run.
+fn bench<T>(name: &str, f: impl Fn() -> T) {
+    let start = Instant::now();
+    let mut runs = 0u32;
+    while runs < 10 || start.elapsed() < BUDGET {
+        black_box(f());
+        runs += 1;
+    }
+    println!(
+        "  {name:<24} {:>10.1?} ({runs} runs)",
+        start.elapsed() / runs
+    );
+}
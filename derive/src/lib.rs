@@ -0,0 +1,471 @@
+//! Derive macro that generates `FromStr` for the structs and enums that puzzle input lines are parsed into.
+//! The generated code matches lines with the scanner behind `advent_of_code::scan!`, see `AocParse` for the attributes.
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Data, DeriveInput, Error, Field, Fields, GenericArgument, Ident, LitStr, Path,
+    PathArguments, Result, Type, parse_macro_input,
+};
+
+/// Derives `FromStr` with `Err = ParseError` from a pattern of literals and placeholders.
+///
+/// - `#[aoc(pattern = "...")]` on a struct, or on every variant of an enum. `{name}` parses the field `name`,
+///   `{}` parses the next field in declaration order, `{_}` skips a value and `{..}` matches a list of
+///   `key: value` pairs.
+///   The variants of an enum are tried in order, if none matches the error of the one that got furthest is returned.
+/// - `#[aoc(key_value)]` on a struct is short for `#[aoc(pattern = "{..}")]`.
+/// - `#[aoc(separator = ", ", assign = ": ")]` next to the pattern changes how pairs and keys are separated.
+/// - `#[aoc(key = "...")]` on a field matched by `{..}` sets its key, which defaults to the field name.
+///   Fields of type `Option<T>` are `None` when their key is missing, for other fields it is an error.
+///
+/// ```ignore
+/// #[derive(AocParse)]
+/// #[aoc(pattern = "Sue {id}: {..}")]
+/// struct Aunt {
+///     id: u64,
+///     cats: Option<u64>,
+///     #[aoc(key = "perfumes")]
+///     perfume: Option<u64>,
+/// }
+/// ```
+#[proc_macro_derive(AocParse, attributes(aoc))]
+pub fn derive_aoc_parse(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "AocParse does not support generic types",
+        ));
+    }
+
+    let name = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let options = Options::parse(&input.attrs, input.ident.span())?;
+            let parser = parser(&syn::parse_quote!(#name), &data.fields, &options)?;
+            quote! {
+                let line = s;
+                #parser
+            }
+        }
+        Data::Enum(data) => {
+            if let Some(attr) = input.attrs.iter().find(|attr| attr.path().is_ident("aoc")) {
+                return Err(Error::new(
+                    attr.span(),
+                    "put #[aoc(pattern = \"...\")] on each variant of an enum",
+                ));
+            }
+            if data.variants.is_empty() {
+                return Err(Error::new(
+                    input.ident.span(),
+                    "AocParse needs at least one variant",
+                ));
+            }
+
+            let variants = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let options = Options::parse(&variant.attrs, variant.ident.span())?;
+                    let ident = &variant.ident;
+                    let path = syn::parse_quote!(#name::#ident);
+                    let parser = parser(&path, &variant.fields, &options)?;
+                    let function = format_ident!("parse_{}", ident);
+                    Ok(quote! {
+                        #[allow(non_snake_case)]
+                        fn #function(line: &str) -> ::core::result::Result<#name, ::advent_of_code::utils::ParseError> {
+                            #parser
+                        }
+                        match #function(s) {
+                            ::core::result::Result::Ok(value) => return ::core::result::Result::Ok(value),
+                            ::core::result::Result::Err(error) => {
+                                if best.as_ref().is_none_or(|best: &::advent_of_code::utils::ParseError| {
+                                    (error.line, error.column) > (best.line, best.column)
+                                }) {
+                                    best = ::core::option::Option::Some(error);
+                                }
+                            }
+                        }
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            quote! {
+                let mut best = ::core::option::Option::None;
+                #(#variants)*
+                ::core::result::Result::Err(best.unwrap())
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "AocParse does not support unions",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl ::core::str::FromStr for #name {
+            type Err = ::advent_of_code::utils::ParseError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                #body
+            }
+        }
+    })
+}
+
+/// The `#[aoc(...)]` options of a struct or a variant.
+struct Options {
+    pattern: LitStr,
+    separator: String,
+    assign: String,
+}
+
+impl Options {
+    fn parse(attrs: &[Attribute], span: Span) -> Result<Self> {
+        let mut pattern = None;
+        let mut key_value = false;
+        let mut separator = ", ".to_string();
+        let mut assign = ": ".to_string();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("aoc")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("pattern") {
+                    pattern = Some(meta.value()?.parse::<LitStr>()?);
+                } else if meta.path.is_ident("key_value") {
+                    key_value = true;
+                } else if meta.path.is_ident("separator") {
+                    separator = meta.value()?.parse::<LitStr>()?.value();
+                } else if meta.path.is_ident("assign") {
+                    assign = meta.value()?.parse::<LitStr>()?.value();
+                } else {
+                    return Err(
+                        meta.error("expected `pattern`, `key_value`, `separator` or `assign`")
+                    );
+                }
+                Ok(())
+            })?;
+        }
+
+        let pattern = match (pattern, key_value) {
+            (Some(pattern), false) => pattern,
+            (None, true) => LitStr::new("{..}", span),
+            (Some(pattern), true) => {
+                return Err(Error::new(
+                    pattern.span(),
+                    "use either `pattern` or `key_value`, a pattern can contain `{..}` for the pairs",
+                ));
+            }
+            (None, false) => {
+                return Err(Error::new(span, "missing #[aoc(pattern = \"...\")]"));
+            }
+        };
+        if separator.is_empty() || assign.is_empty() {
+            return Err(Error::new(
+                span,
+                "`separator` and `assign` must not be empty",
+            ));
+        }
+
+        Ok(Self {
+            pattern,
+            separator,
+            assign,
+        })
+    }
+}
+
+/// A placeholder of a pattern.
+enum Placeholder {
+    /// `{}`, the next field in declaration order.
+    Next,
+    /// `{name}`, the field with that name.
+    Named(String),
+    /// `{_}`, a value that is not stored in a field.
+    Ignored,
+    /// `{..}`, a list of key-value pairs.
+    Pairs,
+}
+
+/// Splits a pattern into the placeholders and the pattern with each of them replaced by `{}`, as the scanner expects.
+fn parse_pattern(pattern: &LitStr) -> Result<(String, Vec<Placeholder>)> {
+    let text = pattern.value();
+    let mut scanner_pattern = String::new();
+    let mut placeholders = vec![];
+    let mut rest = text.as_str();
+    let mut after_placeholder = false;
+
+    while let Some(start) = rest.find(['{', '}']) {
+        let Some(end) = rest[start..]
+            .find('}')
+            .filter(|_| rest[start..].starts_with('{'))
+        else {
+            return Err(Error::new(pattern.span(), "unmatched brace in the pattern"));
+        };
+        if start == 0 && after_placeholder {
+            return Err(Error::new(
+                pattern.span(),
+                "placeholders of a pattern must be separated by a literal",
+            ));
+        }
+
+        let name = rest[start + 1..start + end].trim();
+        placeholders.push(match name {
+            "" => Placeholder::Next,
+            "_" => Placeholder::Ignored,
+            ".." => Placeholder::Pairs,
+            _ if syn::parse_str::<Ident>(name).is_ok() => Placeholder::Named(name.to_string()),
+            _ => {
+                return Err(Error::new(
+                    pattern.span(),
+                    format!("`{{{name}}}` is not a placeholder, expected `{{}}`, `{{field}}`, `{{_}}` or `{{..}}`"),
+                ));
+            }
+        });
+
+        scanner_pattern.push_str(&rest[..start]);
+        scanner_pattern.push_str("{}");
+        rest = &rest[start + end + 1..];
+        after_placeholder = true;
+    }
+    scanner_pattern.push_str(rest);
+
+    Ok((scanner_pattern, placeholders))
+}
+
+/// Generates the statements that parse `line` into `path`, evaluating to a `Result`.
+fn parser(path: &Path, fields: &Fields, options: &Options) -> Result<TokenStream> {
+    let (pattern, placeholders) = parse_pattern(&options.pattern)?;
+    let fields: Vec<&Field> = fields.iter().collect();
+    let binding = |i: usize| format_ident!("field_{}", i);
+
+    let mut parsed = vec![None; fields.len()];
+    let mut steps = vec![];
+    let mut next = 0;
+    let mut pairs = None;
+
+    for placeholder in &placeholders {
+        let i = match placeholder {
+            Placeholder::Next => {
+                next += 1;
+                next - 1
+            }
+            Placeholder::Named(name) => fields
+                .iter()
+                .position(|field| field.ident.as_ref().is_some_and(|ident| ident == name))
+                .ok_or_else(|| {
+                    Error::new(
+                        options.pattern.span(),
+                        format!("there is no field `{name}` to parse"),
+                    )
+                })?,
+            Placeholder::Ignored => {
+                steps.push(quote! { scanner.next_field()?; });
+                continue;
+            }
+            Placeholder::Pairs => {
+                if pairs.is_some() {
+                    return Err(Error::new(
+                        options.pattern.span(),
+                        "a pattern can only contain one `{..}`",
+                    ));
+                }
+                pairs = Some(steps.len());
+                steps.push(quote! { let pairs = scanner.next_field()?; });
+                continue;
+            }
+        };
+
+        let Some(field) = fields.get(i) else {
+            return Err(Error::new(
+                options.pattern.span(),
+                "the pattern has more placeholders than there are fields",
+            ));
+        };
+        if parsed[i].replace(()).is_some() {
+            return Err(Error::new(
+                options.pattern.span(),
+                format!("field {} is parsed twice", field_name(field, i)),
+            ));
+        }
+
+        let ty = &field.ty;
+        let binding = binding(i);
+        steps.push(quote! {
+            let #binding = ::advent_of_code::utils::scan::parse_value::<#ty>(line, scanner.next_field()?)?;
+        });
+    }
+
+    let mut declarations = vec![];
+    let mut arms = vec![];
+    let mut keys = vec![];
+    let mut finish = vec![];
+
+    for (i, field) in fields.iter().enumerate() {
+        let key = field_key(field)?;
+        if parsed[i].is_some() {
+            if let Some(key) = key {
+                return Err(Error::new(
+                    key.span(),
+                    "`key` only applies to fields matched by `{..}`",
+                ));
+            }
+            continue;
+        }
+        if pairs.is_none() {
+            return Err(Error::new(
+                field.span(),
+                format!(
+                    "field {} does not appear in the pattern",
+                    field_name(field, i)
+                ),
+            ));
+        }
+
+        let key = match key {
+            Some(key) => key.value(),
+            None => field
+                .ident
+                .as_ref()
+                .map(|ident| ident.to_string().trim_start_matches("r#").to_string())
+                .ok_or_else(|| {
+                    Error::new(
+                        field.span(),
+                        "fields matched by `{..}` need a name or a `key`",
+                    )
+                })?,
+        };
+
+        let binding = binding(i);
+        let (value_ty, optional) = match option_inner(&field.ty) {
+            Some(inner) => (inner, true),
+            None => (&field.ty, false),
+        };
+        declarations.push(quote! {
+            let mut #binding: ::core::option::Option<#value_ty> = ::core::option::Option::None;
+        });
+        arms.push(quote! {
+            #key => #binding = ::core::option::Option::Some(
+                ::advent_of_code::utils::scan::parse_value::<#value_ty>(line, value)?
+            ),
+        });
+        if !optional {
+            finish.push(quote! {
+                let ::core::option::Option::Some(#binding) = #binding else {
+                    return ::core::result::Result::Err(
+                        ::advent_of_code::utils::scan::missing_key(line, pairs, #key)
+                    );
+                };
+            });
+        }
+        keys.push(format!("`{key}`"));
+    }
+
+    if let Some(index) = pairs {
+        let separator = &options.separator;
+        let assign = &options.assign;
+        let value = if arms.is_empty() {
+            quote! { _ }
+        } else {
+            quote! { value }
+        };
+        let expected = match keys.len() {
+            0 => "no key".to_string(),
+            1 => keys[0].clone(),
+            _ => format!("one of {}", keys.join(", ")),
+        };
+        steps.insert(
+            index + 1,
+            quote! {
+                #(#declarations)*
+                for pair in ::advent_of_code::utils::scan::key_values(line, pairs, #separator, #assign) {
+                    let (key, #value) = pair?;
+                    match key {
+                        #(#arms)*
+                        _ => {
+                            return ::core::result::Result::Err(
+                                ::advent_of_code::utils::ParseError::new(line, key, #expected)
+                            );
+                        }
+                    }
+                }
+                #(#finish)*
+            },
+        );
+    }
+
+    let bindings = (0..fields.len()).map(binding);
+    let value = match fields.first().map(|field| &field.ident) {
+        None => quote! { #path },
+        Some(None) => quote! { #path(#(#bindings),*) },
+        Some(Some(_)) => {
+            let names = fields.iter().map(|field| &field.ident);
+            quote! { #path { #(#names: #bindings),* } }
+        }
+    };
+
+    let scanner = if placeholders.is_empty() {
+        quote! { scanner }
+    } else {
+        quote! { mut scanner }
+    };
+    Ok(quote! {
+        let #scanner = ::advent_of_code::utils::scan::Scanner::new(#pattern, line)?;
+        #(#steps)*
+        scanner.finish()?;
+        ::core::result::Result::Ok(#value)
+    })
+}
+
+/// The `#[aoc(key = "...")]` of a field.
+fn field_key(field: &Field) -> Result<Option<LitStr>> {
+    let mut key = None;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("aoc"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("key") {
+                key = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `key`"))
+            }
+        })?;
+    }
+    Ok(key)
+}
+
+fn field_name(field: &Field, index: usize) -> String {
+    match &field.ident {
+        Some(ident) => format!("`{ident}`"),
+        None => index.to_string(),
+    }
+}
+
+/// The `T` of a field of type `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) if arguments.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
@@ -0,0 +1,26 @@
+use advent_of_code::days::day02::*;
+advent_of_code::solution!(2, Day02);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::template::Solution;
+
+    #[test]
+    fn test_part_one() {
+        let boxes = Day02::parse(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(Day02::part_one(&boxes), Some(58 + 43));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let boxes = Day02::parse(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(Day02::part_two(&boxes), Some(34 + 14));
+    }
+
+    #[test]
+    fn test_solve() {
+        let result = Day02::solve(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, (Some(101), Some(48)));
+    }
+}
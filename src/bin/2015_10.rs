@@ -0,0 +1,16 @@
+use advent_of_code::days::day10::*;
+advent_of_code::solution!(10);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_look_say() {
+        let result = solve(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            1,
+        );
+        assert_eq!(result, "312211".to_string());
+    }
+}
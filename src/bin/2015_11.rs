@@ -0,0 +1,39 @@
+use advent_of_code::days::day11::*;
+advent_of_code::solution!(11);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_increment() {
+        let input = "hijklmmz";
+        let mut bytes = input.as_bytes().to_owned();
+        increment_password(&mut bytes);
+        assert_eq!(bytes, "hijklmna".as_bytes().to_owned())
+    }
+    #[test]
+    fn test_valid_pass() {
+        let input = "haabcmmz";
+        let bytes = input.as_bytes().to_owned();
+        let result = validate_password(&bytes);
+        assert!(result);
+    }
+    #[test]
+    fn test_invalid_pass() {
+        let input = "haabdmmz";
+        let bytes = input.as_bytes().to_owned();
+        let result = validate_password(&bytes);
+        assert!(!result);
+
+        let input = "haabcaaz";
+        let bytes = input.as_bytes().to_owned();
+        let result = validate_password(&bytes);
+        assert!(!result);
+
+        let input = "haabcmmi";
+        let bytes = input.as_bytes().to_owned();
+        let result = validate_password(&bytes);
+        assert!(!result);
+    }
+}
@@ -0,0 +1,5 @@
+use advent_of_code::days::day12::*;
+advent_of_code::solution!(12);
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,21 @@
+use advent_of_code::days::day14::*;
+advent_of_code::solution!(14);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let reindeer = parse_input(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        let result = solve_max_distance(&reindeer, 1000);
+        assert_eq!(result, Some(1120));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let reindeer = parse_input(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        let result = score_reindeer(&reindeer, 1000);
+        assert_eq!(result, Some(689));
+    }
+}
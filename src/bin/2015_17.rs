@@ -0,0 +1,21 @@
+use advent_of_code::days::day17::*;
+advent_of_code::solution!(17);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let containers = parse_input(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        let (result, _) = solve(&containers, 25);
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let containers = parse_input(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        let (_, result) = solve(&containers, 25);
+        assert_eq!(result, 3);
+    }
+}
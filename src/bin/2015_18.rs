@@ -0,0 +1,21 @@
+use advent_of_code::days::day18::*;
+advent_of_code::solution!(18);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let mut grid = parse_input(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        let result = animate(&mut grid, 4, Part::One);
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let mut grid = parse_input(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        let result = animate(&mut grid, 5, Part::Two);
+        assert_eq!(result, 17);
+    }
+}
@@ -0,0 +1,15 @@
+use advent_of_code::days::day22::*;
+advent_of_code::solution!(22);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = &advent_of_code::template::read_file("examples", YEAR, DAY);
+        let boss = parse_input(input);
+        let result = solve(10, 250, boss.health, boss.damage, false);
+        assert_eq!(result, Some(250 - 24));
+    }
+}
@@ -0,0 +1,22 @@
+use advent_of_code::days::day23::*;
+advent_of_code::solution!(23);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        // the example only touches register `a`, the answer is register `b`.
+        let input = advent_of_code::template::read_file("examples", YEAR, DAY);
+        assert_eq!(run(&parse_input(&input), 0), [2, 0]);
+        assert_eq!(part_one(&input), Some(0));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::template::read_file("examples", YEAR, DAY);
+        assert_eq!(run(&parse_input(&input), 1), [7, 0]);
+        assert_eq!(part_two(&input), Some(0));
+    }
+}
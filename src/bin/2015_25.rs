@@ -0,0 +1,13 @@
+use advent_of_code::days::day25::*;
+advent_of_code::solution!(25, 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(33071741));
+    }
+}
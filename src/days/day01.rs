@@ -1,6 +1,4 @@
-advent_of_code::solution!(1);
-
-fn parse_input(input: &str) -> Vec<i64> {
+pub fn parse_input(input: &str) -> Vec<i64> {
     input
         .trim()
         .chars()
@@ -29,20 +27,3 @@ pub fn part_two(input: &str) -> Option<usize> {
     }
     None
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(-1));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(5));
-    }
-}
@@ -0,0 +1,54 @@
+use crate::template::Solution;
+use crate::utils::bytes;
+
+pub struct Day02;
+
+pub fn surface_area(v: &[u64]) -> (u64, u64) {
+    assert!(v.len() == 3);
+    let (l, w, h) = (v[0], v[1], v[2]);
+    let sides = [l * w, w * h, h * l];
+    (
+        sides.into_iter().fold(0, |acc, e| acc + e * 2),
+        sides.into_iter().min().unwrap(),
+    )
+}
+
+pub fn ribbon(v: &[u64]) -> (u64, u64) {
+    assert!(v.len() == 3);
+    let (l, w, h) = (v[0], v[1], v[2]);
+    let sides = [l + w, w + h, h + l];
+    (l * w * h, sides.into_iter().min().unwrap())
+}
+
+impl Solution for Day02 {
+    type Parsed<'a> = Vec<[u64; 3]>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        bytes::lines(input.as_bytes())
+            .map(|line| {
+                let mut dims = bytes::integers(line);
+                [(); 3].map(|_| dims.next().expect("a box has 3 dimensions"))
+            })
+            .collect()
+    }
+
+    fn part_one(boxes: &Self::Parsed<'_>) -> Option<u64> {
+        let mut sum = 0;
+        for x in boxes {
+            let (sf, m) = surface_area(x);
+            sum += sf + m;
+        }
+        Some(sum)
+    }
+
+    fn part_two(boxes: &Self::Parsed<'_>) -> Option<u64> {
+        let mut sum = 0;
+        for x in boxes {
+            let (vol, r) = ribbon(x);
+            sum += vol + r * 2;
+        }
+        Some(sum)
+    }
+}
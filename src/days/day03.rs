@@ -0,0 +1,33 @@
+use crate::utils::grid::{Direction, InfiniteGrid, Point};
+use crate::utils::input::or_panic;
+
+pub fn parse_input(input: &str) -> Vec<Direction> {
+    let line = input.lines().next().unwrap().trim();
+    or_panic(Direction::parse_sequence(line))
+}
+
+/// Delivers a present to the start and to every house along the path.
+pub fn deliver<'a>(houses: &mut InfiniteGrid<usize>, path: impl Iterator<Item = &'a Direction>) {
+    let mut pos = Point::ORIGIN;
+    houses[pos] += 1;
+    for &dir in path {
+        pos += dir;
+        houses[pos] += 1;
+    }
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let dirs = parse_input(input);
+    let mut houses = InfiniteGrid::new(0);
+    deliver(&mut houses, dirs.iter());
+    Some(houses.iter().filter(|&(_, &n)| n > 0).count())
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let dirs = parse_input(input);
+    let mut houses = InfiniteGrid::new(0);
+    // Santa and the robot take turns
+    deliver(&mut houses, dirs.iter().step_by(2));
+    deliver(&mut houses, dirs.iter().skip(1).step_by(2));
+    Some(houses.iter().filter(|&(_, &n)| n > 0).count())
+}
@@ -1,10 +1,8 @@
-advent_of_code::solution!(4);
-
-fn get_hash(secret: &str, digit: usize) -> md5::Digest {
+pub fn get_hash(secret: &str, digit: usize) -> md5::Digest {
     md5::compute(format!("{secret}{digit}"))
 }
 
-fn has_leading_zeros(digest: md5::Digest, n_zeros: usize) -> bool {
+pub fn has_leading_zeros(digest: md5::Digest, n_zeros: usize) -> bool {
     let bytes = digest.0;
     let full_zero_bytes = n_zeros / 2;
     let remaining_half = n_zeros % 2;
@@ -43,20 +41,3 @@ pub fn part_two(input: &str) -> Option<usize> {
         i += 1;
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(609043));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6742839));
-    }
-}
@@ -1,10 +1,8 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(5);
-
 // Part one rules
 
-fn three_vowels(s: &str) -> bool {
+pub fn three_vowels(s: &str) -> bool {
     s.chars()
         .map(|c| match c {
             'a' | 'u' | 'i' | 'e' | 'o' => 1,
@@ -14,12 +12,12 @@ fn three_vowels(s: &str) -> bool {
         >= 3
 }
 
-fn duplicates(s: &str) -> bool {
+pub fn duplicates(s: &str) -> bool {
     // Assumes only ASCII
     s.as_bytes().windows(2).any(|w| w[0] == w[1])
 }
 
-fn doesnt_contain_sets(s: &str) -> bool {
+pub fn doesnt_contain_sets(s: &str) -> bool {
     let sets = ["ab", "cd", "pq", "xy"];
     for set in sets {
         if s.contains(set) {
@@ -31,12 +29,12 @@ fn doesnt_contain_sets(s: &str) -> bool {
 
 // Part 2 rules
 
-fn duplicates_split_by_one(s: &str) -> bool {
+pub fn duplicates_split_by_one(s: &str) -> bool {
     // Assumes only ASCII
     s.as_bytes().windows(3).any(|w| w[0] == w[2])
 }
 
-fn pairs_non_overlapping(s: &str) -> bool {
+pub fn pairs_non_overlapping(s: &str) -> bool {
     let mut map: HashMap<(u8, u8), usize> = HashMap::new();
 
     for (i, w) in s.as_bytes().windows(2).enumerate() {
@@ -82,24 +80,3 @@ pub fn part_two(input: &str) -> Option<u64> {
     }
     Some(count)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
-        assert_eq!(result, Some(2));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(2));
-    }
-}
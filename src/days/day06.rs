@@ -0,0 +1,86 @@
+use crate::mnemonics;
+use crate::utils::ParseError;
+use crate::utils::bits::BitSet;
+use crate::utils::grid::Grid;
+use crate::utils::input::or_panic;
+
+#[derive(Debug)]
+pub enum Operation {
+    On,
+    Off,
+    Toggle,
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    pub op: Operation,
+    pub corner1: (usize, usize),
+    pub corner2: (usize, usize),
+}
+
+pub fn parse_instruction(line: &str) -> Result<Instruction, ParseError> {
+    let (op, x1, y1, x2, y2) = mnemonics!(line, {
+        ["turn on" {x1: usize} "," {y1: usize} "through" {x2: usize} "," {y2: usize}] => {
+            (Operation::On, x1, y1, x2, y2)
+        },
+        ["turn off" {x1: usize} "," {y1: usize} "through" {x2: usize} "," {y2: usize}] => {
+            (Operation::Off, x1, y1, x2, y2)
+        },
+        ["toggle" {x1: usize} "," {y1: usize} "through" {x2: usize} "," {y2: usize}] => {
+            (Operation::Toggle, x1, y1, x2, y2)
+        },
+    })?;
+    Ok(Instruction {
+        op,
+        corner1: (x1, y1),
+        corner2: (x2, y2),
+    })
+}
+
+pub fn parse_input(input: &str) -> Vec<Instruction> {
+    input
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .map(|l| or_panic(parse_instruction(l).map_err(|e| e.within(input, l))))
+        .collect()
+}
+
+/// Applies `f` to every light in the rectangle of an instruction.
+pub fn apply<T>(grid: &mut Grid<T>, ins: &Instruction, f: impl Fn(&mut T)) {
+    for y in ins.corner1.1..=ins.corner2.1 {
+        grid.row_mut(y)[ins.corner1.0..=ins.corner2.0]
+            .iter_mut()
+            .for_each(&f);
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let instructions = parse_input(input);
+    // One bit per light, row by row, so the lights of a row in a rectangle are updated a word at a time
+    let mut lights: BitSet = BitSet::with_capacity(1_000 * 1_000);
+    for ins in instructions {
+        for y in ins.corner1.1..=ins.corner2.1 {
+            let row = y * 1_000 + ins.corner1.0..y * 1_000 + ins.corner2.0 + 1;
+            match ins.op {
+                Operation::On => lights.insert_range(row),
+                Operation::Off => lights.remove_range(row),
+                Operation::Toggle => lights.toggle_range(row),
+            }
+        }
+    }
+    Some(lights.len() as u64)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let instructions = parse_input(input);
+    let mut grid: Grid<u8> = Grid::new(1_000, 1_000, 0);
+    for ins in instructions {
+        match ins.op {
+            Operation::On => apply(&mut grid, &ins, |el| *el += 1),
+            Operation::Off => apply(&mut grid, &ins, |el| *el = el.saturating_sub(1)),
+            Operation::Toggle => apply(&mut grid, &ins, |el| *el += 2),
+        }
+    }
+    Some(grid.cells().iter().map(|&x| x as u64).sum())
+}
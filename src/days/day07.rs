@@ -0,0 +1,129 @@
+use crate::mnemonics;
+use crate::utils::input::or_panic;
+use crate::utils::lexer::{FromToken, Lexeme, Token};
+use crate::utils::{Interner, ParseError};
+
+/// A signal or the wire it comes from, identified by its id in [`Circuit::wires`].
+#[derive(Debug, Clone, Copy)]
+pub enum Operand {
+    Val(u16),
+    Wire(usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Expr {
+    Val(u16),
+    Wire(Operand),
+    Not(Operand),
+    And(Operand, Operand),
+    Or(Operand, Operand),
+    LShift(Operand, u8),
+    RShift(Operand, u8),
+}
+
+#[derive(Debug, Clone)]
+pub struct Circuit<'a> {
+    pub wires: Interner<'a>,
+    /// The expression driving each wire, indexed by wire id.
+    pub exprs: Vec<Option<Expr>>,
+}
+
+pub fn parse_input(input: &str) -> Circuit<'_> {
+    let mut wires = Interner::new();
+    let mut exprs = vec![];
+    input
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .for_each(|l| {
+            let (k, v) = or_panic(parse_line(l, &mut wires).map_err(|e| e.within(input, l)));
+            exprs.resize(wires.len(), None);
+            exprs[k] = Some(v);
+        });
+    exprs.resize(wires.len(), None);
+    Circuit { wires, exprs }
+}
+
+/// An operand as written in the input, before its wire is interned.
+pub enum Source<'a> {
+    Val(u16),
+    Wire(&'a str),
+}
+
+impl<'a> FromToken<'a> for Source<'a> {
+    fn from_token(line: &'a str, lexeme: &Lexeme<'a>) -> Result<Self, ParseError> {
+        match lexeme.token {
+            Token::Ident(wire) => Ok(Source::Wire(wire)),
+            _ => u16::from_token(line, lexeme).map(Source::Val),
+        }
+    }
+}
+
+pub fn parse_line<'a>(
+    line: &'a str,
+    wires: &mut Interner<'a>,
+) -> Result<(usize, Expr), ParseError> {
+    let mut operand = |source: Source<'a>| match source {
+        Source::Val(v) => Operand::Val(v),
+        Source::Wire(w) => Operand::Wire(wires.intern(w)),
+    };
+    let (expr, target) = mnemonics!(line, {
+        [{v: u16} "->" {t: &str}] => (Expr::Val(v), t),
+        [{a: Source} "->" {t: &str}] => (Expr::Wire(operand(a)), t),
+        ["NOT" {a: Source} "->" {t: &str}] => (Expr::Not(operand(a)), t),
+        [{a: Source} "AND" {b: Source} "->" {t: &str}] => (Expr::And(operand(a), operand(b)), t),
+        [{a: Source} "OR" {b: Source} "->" {t: &str}] => (Expr::Or(operand(a), operand(b)), t),
+        [{a: Source} "LSHIFT" {n: u8} "->" {t: &str}] => (Expr::LShift(operand(a), n), t),
+        [{a: Source} "RSHIFT" {n: u8} "->" {t: &str}] => (Expr::RShift(operand(a), n), t),
+    })?;
+    Ok((wires.intern(target), expr))
+}
+
+pub fn eval(operand: Operand, exprs: &[Option<Expr>], memo: &mut [Option<u16>]) -> u16 {
+    let wire = match operand {
+        // Numeric case
+        Operand::Val(v) => return v,
+        Operand::Wire(wire) => wire,
+    };
+
+    // Check memo
+    if let Some(v) = memo[wire] {
+        return v;
+    }
+
+    // Look up instruction
+    let expr = exprs[wire].unwrap_or_else(|| panic!("Unknown wire"));
+    let result = match expr {
+        Expr::Val(v) => v,
+        Expr::Wire(a) => eval(a, exprs, memo),
+        Expr::Not(a) => !eval(a, exprs, memo),
+        Expr::And(a, b) => eval(a, exprs, memo) & eval(b, exprs, memo),
+        Expr::Or(a, b) => eval(a, exprs, memo) | eval(b, exprs, memo),
+        Expr::LShift(a, n) => eval(a, exprs, memo) << n,
+        Expr::RShift(a, n) => eval(a, exprs, memo) >> n,
+    };
+    memo[wire] = Some(result);
+    result
+}
+
+/// Evaluates the signal on wire `a`.
+pub fn signal_a(circuit: &Circuit) -> Option<u16> {
+    let a = circuit.wires.get("a")?;
+    let mut memo = vec![None; circuit.exprs.len()];
+    Some(eval(Operand::Wire(a), &circuit.exprs, &mut memo))
+}
+
+pub fn part_one(input: &str) -> Option<u16> {
+    signal_a(&parse_input(input))
+}
+
+pub fn part_two(input: &str) -> Option<u16> {
+    let mut circuit = parse_input(input);
+    let wire_a_signal = signal_a(&circuit)?;
+
+    // Overwrite b signal with a
+    if let Some(b) = circuit.wires.get("b") {
+        circuit.exprs[b] = Some(Expr::Val(wire_a_signal));
+    }
+    signal_a(&circuit)
+}
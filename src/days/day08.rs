@@ -1,11 +1,10 @@
-use advent_of_code::utils::input::lines;
+use crate::utils::input::lines;
 use regex::Regex;
 use std::sync::LazyLock;
-advent_of_code::solution!(8);
 
-static RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"\\x[0-9a-fA-F]{2}|\\."#).unwrap());
+pub static RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"\\x[0-9a-fA-F]{2}|\\."#).unwrap());
 
-fn calc_length(line: &str) -> usize {
+pub fn calc_length(line: &str) -> usize {
     let code_len = line.len();
     let inner = &line[1..line.len() - 1]; // strip quotes
     let mut mem_len = code_len - 2;
@@ -31,7 +30,7 @@ pub fn part_one(input: &str) -> Option<usize> {
     )
 }
 
-fn encoded_length(line: &str) -> usize {
+pub fn encoded_length(line: &str) -> usize {
     // start from the two outside quotes
     let mut count = 2;
     for c in line.chars() {
@@ -52,20 +51,3 @@ pub fn part_two(input: &str) -> Option<usize> {
             .sum(),
     )
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(12));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(19));
-    }
-}
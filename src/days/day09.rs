@@ -0,0 +1,57 @@
+use itertools::Itertools;
+
+use crate::scan;
+use crate::utils::Interner;
+use crate::utils::input::or_panic;
+
+#[derive(Debug, Clone)]
+pub struct Routes<'a> {
+    pub cities: Interner<'a>,
+    /// The distance between two cities, indexed by city id.
+    pub distances: Vec<Vec<u64>>,
+}
+
+pub fn parse_input(input: &str) -> Routes<'_> {
+    let mut cities: Interner = Interner::new();
+    let mut edges = vec![];
+
+    for line in input.lines() {
+        let (fr, to, dist_parsed) =
+            or_panic(scan!("{} to {} = {}", line.trim() => (&str, &str, u64)));
+        edges.push((cities.intern(fr), cities.intern(to), dist_parsed));
+    }
+
+    let mut distances = vec![vec![0; cities.len()]; cities.len()];
+    for (fr, to, dist) in edges {
+        distances[fr][to] = dist;
+        distances[to][fr] = dist;
+    }
+    Routes { cities, distances }
+}
+
+pub fn path_distance(distances: &[Vec<u64>], path: &[usize]) -> u64 {
+    path.windows(2).map(|w| distances[w[0]][w[1]]).sum()
+}
+
+pub fn solve(input: &str) -> (u64, u64) {
+    let routes = parse_input(input);
+    let n = routes.cities.len();
+    let mut min_dist = u64::MAX;
+    let mut max_dist = 0;
+    for perm in (0..n).permutations(n) {
+        let dist = path_distance(&routes.distances, &perm);
+        min_dist = min_dist.min(dist);
+        max_dist = max_dist.max(dist);
+    }
+    (min_dist, max_dist)
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let (min_dist, _max_dist) = solve(input);
+    Some(min_dist)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let (_min_dist, max_dist) = solve(input);
+    Some(max_dist)
+}
@@ -1,12 +1,11 @@
 use serde_json::Value;
-advent_of_code::solution!(12);
 
-fn parse(input: &str) -> Value {
+pub fn parse(input: &str) -> Value {
     serde_json::from_str(input).expect("Invalid JSON")
 }
 
 // Part 1
-fn sum_numbers(v: &Value) -> i64 {
+pub fn sum_numbers(v: &Value) -> i64 {
     match v {
         Value::Number(n) => n.as_i64().unwrap(),
         Value::Array(arr) => arr.iter().map(sum_numbers).sum(),
@@ -21,7 +20,7 @@ pub fn part_one(input: &str) -> Option<i64> {
 }
 
 // Part 2
-fn sum_numbers_wo_red(v: &Value) -> i64 {
+pub fn sum_numbers_wo_red(v: &Value) -> i64 {
     match v {
         Value::Number(n) => n.as_i64().unwrap(),
         Value::Array(arr) => arr.iter().map(sum_numbers_wo_red).sum(),
@@ -41,6 +40,3 @@ pub fn part_two(input: &str) -> Option<i64> {
     let val = parse(input);
     Some(sum_numbers_wo_red(&val))
 }
-
-#[cfg(test)]
-mod tests {}
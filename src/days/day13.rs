@@ -0,0 +1,80 @@
+use itertools::Itertools;
+
+use crate::scan;
+use crate::utils::Interner;
+use crate::utils::input::or_panic;
+
+#[derive(Debug, Clone)]
+pub struct Table<'a> {
+    pub guests: Interner<'a>,
+    /// The happiness a guest gains sitting next to another, indexed by guest id.
+    pub happiness: Vec<Vec<i64>>,
+}
+
+pub fn parse_input(input: &str) -> Table<'_> {
+    let mut guests: Interner = Interner::new();
+    let mut entries = vec![];
+    for line in input.lines() {
+        let (a, change, amount, b) = or_panic(scan!(
+            "{} would {} {} happiness units by sitting next to {}.",
+            line.trim() => (&str, &str, i64, &str)
+        ));
+        let mult: i64 = match change {
+            "gain" => 1,
+            "lose" => -1,
+            _ => panic!(),
+        };
+        entries.push((guests.intern(a), guests.intern(b), amount * mult));
+    }
+
+    let mut happiness = vec![vec![0; guests.len()]; guests.len()];
+    for (a, b, value) in entries {
+        happiness[a][b] = value;
+    }
+    Table { guests, happiness }
+}
+
+pub fn eval(happiness: &[Vec<i64>], order: &[usize]) -> i64 {
+    let mut hap = 0;
+    let n = order.len();
+
+    for i in 0..n {
+        let a = order[i];
+        let b = order[(i + 1) % n];
+        // Add happiness scoe in both directions
+        hap += happiness[a][b];
+        hap += happiness[b][a];
+    }
+    hap
+}
+
+pub fn solve(happiness: &[Vec<i64>]) -> i64 {
+    let mut max_hap = i64::MIN;
+    // keep one element fixed to due to symmetry for performance gains
+    let n = happiness.len();
+    for perm in (1..n).permutations(n - 1) {
+        let mut full = Vec::with_capacity(n);
+        full.push(0);
+        full.extend(perm);
+        let hap = eval(happiness, &full);
+        max_hap = max_hap.max(hap);
+    }
+    max_hap
+}
+
+pub fn part_one(input: &str) -> Option<i64> {
+    let table = parse_input(input);
+    Some(solve(&table.happiness))
+}
+
+pub fn part_two(input: &str) -> Option<i64> {
+    let mut table = parse_input(input);
+    // Add "You", who is indifferent to everyone
+    table.guests.intern("You");
+    let n = table.guests.len();
+    for row in table.happiness.iter_mut() {
+        row.push(0);
+    }
+    table.happiness.push(vec![0; n]);
+    Some(solve(&table.happiness))
+}
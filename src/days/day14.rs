@@ -1,45 +1,31 @@
-advent_of_code::solution!(14);
+use crate::utils::AocParse;
+use crate::utils::input::parse_lines;
 
-#[derive(Debug, Clone)]
-struct Reindeer {
-    _name: String,
-    speed: u64,
-    travel_time: u64,
-    rest_time: u64,
+#[derive(Debug, Clone, AocParse)]
+#[aoc(
+    pattern = "{_} can fly {speed} km/s for {travel_time} seconds, but then must rest for {rest_time} seconds."
+)]
+pub struct Reindeer {
+    pub speed: u64,
+    pub travel_time: u64,
+    pub rest_time: u64,
 }
 
 #[derive(Debug, Clone)]
-struct State {
-    r: Reindeer,
-    resting: bool,
-    travel_time_left: u64,
-    rest_time_left: u64,
-    distance_covered: u64,
-    score: u64,
+pub struct State {
+    pub r: Reindeer,
+    pub resting: bool,
+    pub travel_time_left: u64,
+    pub rest_time_left: u64,
+    pub distance_covered: u64,
+    pub score: u64,
 }
 
-fn parse_input(input: &str) -> Vec<Reindeer> {
-    input
-        .lines()
-        .filter(|l| !l.is_empty())
-        .map(|l| l.trim())
-        .map(|l| {
-            let parts: Vec<&str> = l.split_whitespace().collect();
-            let name = parts[0].to_string();
-            let speed = parts[3].parse().unwrap();
-            let travel_time = parts[6].parse().unwrap();
-            let rest_time = parts[parts.len() - 2].parse().unwrap();
-            Reindeer {
-                _name: name,
-                speed,
-                travel_time,
-                rest_time,
-            }
-        })
-        .collect()
+pub fn parse_input(input: &str) -> Vec<Reindeer> {
+    parse_lines(input)
 }
 
-fn calc_distance(r: &Reindeer, target: u64) -> u64 {
+pub fn calc_distance(r: &Reindeer, target: u64) -> u64 {
     let total_time = r.travel_time + r.rest_time;
     let n_whole = target / total_time;
     let remainder = target % total_time;
@@ -52,7 +38,7 @@ fn calc_distance(r: &Reindeer, target: u64) -> u64 {
     result
 }
 
-fn solve_max_distance(v: &[Reindeer], target: u64) -> Option<u64> {
+pub fn solve_max_distance(v: &[Reindeer], target: u64) -> Option<u64> {
     v.iter().map(|r| calc_distance(r, target)).max()
 }
 
@@ -61,7 +47,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     solve_max_distance(&reindeer, 2503)
 }
 
-fn score_reindeer(v: &[Reindeer], target: u64) -> Option<u64> {
+pub fn score_reindeer(v: &[Reindeer], target: u64) -> Option<u64> {
     let mut states: Vec<State> = v
         .iter()
         .map(|r| State {
@@ -114,22 +100,3 @@ pub fn part_two(input: &str) -> Option<u64> {
     let reindeer = parse_input(input);
     score_reindeer(&reindeer, 2503)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let reindeer = parse_input(&advent_of_code::template::read_file("examples", DAY));
-        let result = solve_max_distance(&reindeer, 1000);
-        assert_eq!(result, Some(1120));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let reindeer = parse_input(&advent_of_code::template::read_file("examples", DAY));
-        let result = score_reindeer(&reindeer, 1000);
-        assert_eq!(result, Some(689));
-    }
-}
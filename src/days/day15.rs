@@ -1,22 +1,28 @@
-advent_of_code::solution!(15);
+use crate::utils::AocParse;
+use crate::utils::input::parse_lines;
 
-#[derive(Debug, Clone)]
-struct Ingredient {
-    _name: String,
-    cap: i64,
-    dur: i64,
-    fla: i64,
-    tex: i64,
-    cal: i64,
+#[derive(Debug, Clone, AocParse)]
+#[aoc(pattern = "{_}: {..}", assign = " ")]
+pub struct Ingredient {
+    #[aoc(key = "capacity")]
+    pub cap: i64,
+    #[aoc(key = "durability")]
+    pub dur: i64,
+    #[aoc(key = "flavor")]
+    pub fla: i64,
+    #[aoc(key = "texture")]
+    pub tex: i64,
+    #[aoc(key = "calories")]
+    pub cal: i64,
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Score {
-    cap: i64,
-    dur: i64,
-    fla: i64,
-    tex: i64,
-    cal: i64,
+pub struct Score {
+    pub cap: i64,
+    pub dur: i64,
+    pub fla: i64,
+    pub tex: i64,
+    pub cal: i64,
 }
 
 impl Score {
@@ -45,33 +51,11 @@ impl Score {
     }
 }
 
-fn parse_input(input: &str) -> Vec<Ingredient> {
-    input
-        .lines()
-        .filter(|l| !l.is_empty())
-        .map(|l| l.trim())
-        .map(|l| {
-            let (name, properties) = l.split_once(": ").expect("Invalid syntax");
-            let parts: Vec<&str> = properties.split(", ").collect();
-            let mut props: Vec<i64> = Vec::with_capacity(5);
-            debug_assert!(parts.len() == 5);
-            for p in parts {
-                let (_prop, val) = p.split_once(" ").expect("Invalid property syntax");
-                props.push(val.parse().unwrap());
-            }
-            Ingredient {
-                _name: name.to_owned(),
-                cap: props[0],
-                dur: props[1],
-                fla: props[2],
-                tex: props[3],
-                cal: props[4],
-            }
-        })
-        .collect()
+pub fn parse_input(input: &str) -> Vec<Ingredient> {
+    parse_lines(input)
 }
 
-fn upper_bound(remaining: i64, cur: &Score, ings: &[Ingredient]) -> i64 {
+pub fn upper_bound(remaining: i64, cur: &Score, ings: &[Ingredient]) -> i64 {
     // Loose upper bound, find max value of each ingredient and assign remaining to that
     // Actually not beneficial to precompute and store in vector for such small inputs (benchmarked), so
     // recalculated every time here
@@ -88,7 +72,7 @@ fn upper_bound(remaining: i64, cur: &Score, ings: &[Ingredient]) -> i64 {
     cap * dur * fla * tex
 }
 
-fn dfs(
+pub fn dfs(
     idx: usize,
     remaining: i64,
     cur: Score,
@@ -127,7 +111,7 @@ fn dfs(
     }
 }
 
-fn solve(ings: &[Ingredient], total: i64, calorie_target: Option<i64>) -> u64 {
+pub fn solve(ings: &[Ingredient], total: i64, calorie_target: Option<i64>) -> u64 {
     let mut best = 0;
     let cur = Score::new();
     dfs(0, total, cur, ings, &mut best, calorie_target);
@@ -143,20 +127,3 @@ pub fn part_two(input: &str) -> Option<u64> {
     let ingredients = parse_input(input);
     Some(solve(&ingredients, 100, Some(500)))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(62842880));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(57600000));
-    }
-}
@@ -0,0 +1,112 @@
+use crate::utils::AocParse;
+use crate::utils::input::parse_lines;
+
+pub const TARGET_AUNT: Aunt = Aunt {
+    id: 0,
+    children: Some(3),
+    cats: Some(7),
+    samoyeds: Some(2),
+    pomeranians: Some(3),
+    akitas: Some(0),
+    vizslas: Some(0),
+    goldfish: Some(5),
+    trees: Some(3),
+    cars: Some(2),
+    perfumes: Some(1),
+};
+
+#[derive(Debug, Clone, Copy, AocParse)]
+#[aoc(pattern = "Sue {id}: {..}")]
+pub struct Aunt {
+    pub id: u64,
+    pub children: Option<u64>,
+    pub cats: Option<u64>,
+    pub samoyeds: Option<u64>,
+    pub pomeranians: Option<u64>,
+    pub akitas: Option<u64>,
+    pub vizslas: Option<u64>,
+    pub goldfish: Option<u64>,
+    pub trees: Option<u64>,
+    pub cars: Option<u64>,
+    pub perfumes: Option<u64>,
+}
+
+#[inline(always)]
+pub fn check_plausible(a: Option<u64>, target: Option<u64>) -> bool {
+    match a {
+        None => true,
+        Some(v) => v == target.unwrap(),
+    }
+}
+
+#[inline(always)]
+pub fn check_plausible_gt(a: Option<u64>, target: Option<u64>) -> bool {
+    match a {
+        None => true,
+        Some(v) => v > target.unwrap(),
+    }
+}
+
+#[inline(always)]
+pub fn check_plausible_lt(a: Option<u64>, target: Option<u64>) -> bool {
+    match a {
+        None => true,
+        Some(v) => v < target.unwrap(),
+    }
+}
+
+impl PartialEq for Aunt {
+    fn eq(&self, other: &Self) -> bool {
+        check_plausible(self.children, other.children)
+            && check_plausible(self.cats, other.cats)
+            && check_plausible(self.samoyeds, other.samoyeds)
+            && check_plausible(self.pomeranians, other.pomeranians)
+            && check_plausible(self.akitas, other.akitas)
+            && check_plausible(self.vizslas, other.vizslas)
+            && check_plausible(self.goldfish, other.goldfish)
+            && check_plausible(self.trees, other.trees)
+            && check_plausible(self.cars, other.cars)
+            && check_plausible(self.perfumes, other.perfumes)
+    }
+}
+
+impl Aunt {
+    fn part_two_cmp(&self, other: &Self) -> bool {
+        // pomeranians and goldfish shoud be less than other
+        // cats and trees should be greate than other
+        check_plausible(self.children, other.children)
+            && check_plausible_gt(self.cats, other.cats)
+            && check_plausible(self.samoyeds, other.samoyeds)
+            && check_plausible_lt(self.pomeranians, other.pomeranians)
+            && check_plausible(self.akitas, other.akitas)
+            && check_plausible(self.vizslas, other.vizslas)
+            && check_plausible_lt(self.goldfish, other.goldfish)
+            && check_plausible_gt(self.trees, other.trees)
+            && check_plausible(self.cars, other.cars)
+            && check_plausible(self.perfumes, other.perfumes)
+    }
+}
+
+pub fn parse_input(input: &str) -> Vec<Aunt> {
+    parse_lines(input)
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let aunts = parse_input(input);
+    for a in aunts {
+        if a == TARGET_AUNT {
+            return Some(a.id);
+        }
+    }
+    None
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let aunts = parse_input(input);
+    for a in aunts {
+        if a.part_two_cmp(&TARGET_AUNT) {
+            return Some(a.id);
+        }
+    }
+    None
+}
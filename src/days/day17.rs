@@ -0,0 +1,55 @@
+use crate::utils::bits::BitSet;
+use crate::utils::bytes;
+
+pub fn parse_input(input: &str) -> Vec<i32> {
+    bytes::integers(input.as_bytes()).collect()
+}
+
+/// Solves both part one and two in one loop
+pub fn solve(containers: &[i32], target: i32) -> (usize, usize) {
+    let n = containers.len();
+    let mut total_valid = 0usize;
+    let mut min_used_count = 0;
+
+    // Go through the subsets of containers by size, so the first size with any valid subsets is
+    // the minimum number of containers. Subsets advance in place, which also works past 64 containers.
+    for used in 1..=n {
+        let mut subset: BitSet = BitSet::with_capacity(n);
+        subset.insert_range(0..used);
+        let mut valid = 0;
+        loop {
+            let mut sum = 0;
+            for idx in subset.iter() {
+                sum += containers[idx];
+                if sum > target {
+                    break;
+                }
+            }
+            if sum == target {
+                valid += 1;
+            }
+            if !subset.next_k_subset(n) {
+                break;
+            }
+        }
+        if min_used_count == 0 {
+            min_used_count = valid;
+        }
+        total_valid += valid;
+    }
+    (total_valid, min_used_count)
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let mut containers = parse_input(input);
+    containers.sort_unstable_by(|a, b| b.cmp(a));
+    let (total, _) = solve(&containers, 150);
+    Some(total)
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let mut containers = parse_input(input);
+    containers.sort_unstable_by(|a, b| b.cmp(a));
+    let (_, count) = solve(&containers, 150);
+    Some(count)
+}
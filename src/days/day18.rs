@@ -0,0 +1,63 @@
+use crate::utils::grid::Grid;
+use crate::utils::input::or_panic;
+
+pub fn parse_input(input: &str) -> Grid<bool> {
+    let grid: Grid<char> = or_panic(input.parse());
+    grid.map(|&c| c == '#')
+}
+
+#[derive(PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+#[inline(always)]
+pub fn count_neighbors(grid: &Grid<bool>, x: usize, y: usize) -> usize {
+    grid.neighbors8(x, y).filter(|&p| grid[p]).count()
+}
+
+pub fn animate(grid: &mut Grid<bool>, n_iter: usize, part: Part) -> usize {
+    let (nx, ny) = (grid.width(), grid.height());
+    let corners = [(0, 0), (nx - 1, 0), (0, ny - 1), (nx - 1, ny - 1)];
+    if part == Part::Two {
+        // Fix corners always on
+        for c in corners {
+            grid[c] = true;
+        }
+    }
+    let mut next = Grid::new(nx, ny, false);
+
+    for _ in 0..n_iter {
+        for y in 0..ny {
+            for x in 0..nx {
+                // count number of neighbors that are on
+                let n = count_neighbors(grid, x, y);
+                // Toggle on/off
+                let on = grid[(x, y)];
+                // spelled out as the puzzle states the rules for lights that are on and off.
+                #[allow(clippy::nonminimal_bool)]
+                let lit = (on && (n == 2 || n == 3)) || (!on && n == 3);
+                next[(x, y)] = lit;
+            }
+        }
+        if part == Part::Two {
+            // Fix corners always on
+            for c in corners {
+                next[c] = true;
+            }
+        }
+        std::mem::swap(grid, &mut next);
+    }
+    grid.cells().iter().filter(|&&x| x).count()
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let mut grid = parse_input(input);
+    Some(animate(&mut grid, 100, Part::One))
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let mut grid = parse_input(input);
+    Some(animate(&mut grid, 100, Part::Two))
+}
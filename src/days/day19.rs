@@ -1,9 +1,7 @@
 use rand::prelude::*;
 use std::collections::HashSet;
 
-advent_of_code::solution!(19);
-
-fn parse_input(input: &str) -> (Vec<(&str, &str)>, &str) {
+pub fn parse_input(input: &str) -> (Vec<(&str, &str)>, &str) {
     let (rules_str, target) = input.split_once("\n\n").unwrap();
     let target = target.trim();
     let mut rules = vec![];
@@ -32,7 +30,7 @@ pub fn part_one(input: &str) -> Option<usize> {
     Some(set.len())
 }
 
-fn greedy_backtrace(rules: &[(&str, &str)], target: &str, start: &str) -> Option<usize> {
+pub fn greedy_backtrace(rules: &[(&str, &str)], target: &str, start: &str) -> Option<usize> {
     // Go in reverse order from target to start by greedily applying rules in reverse
     let mut n_steps = 0;
     let mut s = target.to_owned();
@@ -52,7 +50,7 @@ fn greedy_backtrace(rules: &[(&str, &str)], target: &str, start: &str) -> Option
     Some(n_steps)
 }
 
-fn length_diff(rule: &(&str, &str)) -> usize {
+pub fn length_diff(rule: &(&str, &str)) -> usize {
     rule.1.len() - rule.0.len()
 }
 
@@ -71,24 +69,3 @@ pub fn part_two(input: &str) -> Option<usize> {
         rules.shuffle(&mut rng);
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
-        assert_eq!(result, Some(4));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(3));
-    }
-}
@@ -1,10 +1,8 @@
-advent_of_code::solution!(20);
-
-fn parse_input(input: &str) -> usize {
+pub fn parse_input(input: &str) -> usize {
     input.trim().parse().unwrap()
 }
 
-fn solve_part1(target: usize) -> Option<usize> {
+pub fn solve_part1(target: usize) -> Option<usize> {
     // Heuristic bound loop
     for heuristic in [40, 20, 10] {
         // Heuristic for max house
@@ -34,7 +32,7 @@ pub fn part_one(input: &str) -> Option<usize> {
     solve_part1(target)
 }
 
-fn solve_part2(target: usize, max_visits: usize) -> Option<usize> {
+pub fn solve_part2(target: usize, max_visits: usize) -> Option<usize> {
     // Heuristic bound loop
     for heuristic in [40, 20, 11] {
         // Heuristic for max house
@@ -65,20 +63,3 @@ pub fn part_two(input: &str) -> Option<usize> {
     let target = parse_input(input);
     solve_part2(target, 50)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(8));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(8));
-    }
-}
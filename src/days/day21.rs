@@ -1,19 +1,18 @@
-#![feature(int_roundings)]
-advent_of_code::solution!(21);
+use crate::utils::input::integer_array;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-struct Item {
-    cost: i32,
-    damage: i32,
-    armor: i32,
+pub struct Item {
+    pub cost: i32,
+    pub damage: i32,
+    pub armor: i32,
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Player {
-    health: i32,
-    damage: i32,
-    armor: i32,
-    cost: i32,
+pub struct Player {
+    pub health: i32,
+    pub damage: i32,
+    pub armor: i32,
+    pub cost: i32,
 }
 
 impl Player {
@@ -36,34 +35,20 @@ impl Player {
 }
 
 #[derive(Debug, Clone)]
-struct Shop {
-    weapons: [Item; 5],
-    armor: [Item; 6],
-    rings: [Item; 8],
+pub struct Shop {
+    pub weapons: [Item; 5],
+    pub armor: [Item; 6],
+    pub rings: [Item; 8],
 }
 
-fn parse_input(input: &str) -> (Player, Shop) {
-    let lines: Vec<&str> = input
-        .lines()
-        .filter(|l| !l.is_empty())
-        .map(|l| l.trim())
-        .collect();
-    let mut params: [i32; 3] = [0, 0, 0];
-    for l in lines {
-        let (p, val) = l.split_once(": ").unwrap();
-        let val = val.parse::<i32>().unwrap();
-        match p {
-            "Hit Points" => params[0] = val,
-            "Damage" => params[1] = val,
-            "Armor" => params[2] = val,
-            _ => panic!(),
-        }
-    }
+pub fn parse_input(input: &str) -> (Player, Shop) {
+    // hit points, damage and armor.
+    let [health, damage, armor] = integer_array(input);
     let boss = Player {
         cost: 0,
-        health: params[0],
-        damage: params[1],
-        armor: params[2],
+        health,
+        damage,
+        armor,
     };
     let weapons = [
         Item {
@@ -179,7 +164,7 @@ fn parse_input(input: &str) -> (Player, Shop) {
     (boss, shop)
 }
 
-fn solve(boss: &Player, shop: &Shop) -> (Option<i32>, Option<i32>) {
+pub fn solve(boss: &Player, shop: &Shop) -> (Option<i32>, Option<i32>) {
     // Mandatory to have one
     let weapons = shop.weapons;
     // Armor 0 -1
@@ -217,12 +202,19 @@ fn solve(boss: &Player, shop: &Shop) -> (Option<i32>, Option<i32>) {
     (res_min, res_max)
 }
 
-fn player_wins(player: &Player, boss: &Player) -> bool {
+pub fn player_wins(player: &Player, boss: &Player) -> bool {
     let player_dmg = (player.damage - boss.armor).max(1);
     let boss_dmg = (boss.damage - player.armor).max(1);
 
-    let player_turns = boss.health.div_ceil(player_dmg);
-    let boss_turns = player.health.div_ceil(boss_dmg);
+    // signed `div_ceil` is unstable, health and damage are positive here.
+    let player_turns = boss
+        .health
+        .cast_unsigned()
+        .div_ceil(player_dmg.cast_unsigned());
+    let boss_turns = player
+        .health
+        .cast_unsigned()
+        .div_ceil(boss_dmg.cast_unsigned());
 
     player_turns <= boss_turns
 }
@@ -238,20 +230,3 @@ pub fn part_two(input: &str) -> Option<i32> {
     let (_, max_loss) = solve(&boss, &shop);
     max_loss
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(28));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-}
@@ -1,15 +1,13 @@
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 
-advent_of_code::solution!(22);
-
 #[derive(Debug, Clone, Copy)]
-struct Boss {
-    health: i32,
-    damage: i32,
+pub struct Boss {
+    pub health: i32,
+    pub damage: i32,
 }
 
-fn parse_input(input: &str) -> Boss {
+pub fn parse_input(input: &str) -> Boss {
     let lines: Vec<i32> = input
         .lines()
         .filter(|l| !l.is_empty())
@@ -25,7 +23,7 @@ fn parse_input(input: &str) -> Boss {
 }
 
 #[derive(Debug, Clone, Copy)]
-enum Spell {
+pub enum Spell {
     Missile,
     Drain,
     Shield,
@@ -33,13 +31,13 @@ enum Spell {
     Recharge,
 }
 // ----- Spell Costs -----
-const MAGIC_MISSILE: i32 = 53;
-const DRAIN: i32 = 73;
-const SHIELD: i32 = 113;
-const POISON: i32 = 173;
-const RECHARGE: i32 = 229;
+pub const MAGIC_MISSILE: i32 = 53;
+pub const DRAIN: i32 = 73;
+pub const SHIELD: i32 = 113;
+pub const POISON: i32 = 173;
+pub const RECHARGE: i32 = 229;
 
-const AVAILABLE_SPELLS: [(Spell, i32); 5] = [
+pub const AVAILABLE_SPELLS: [(Spell, i32); 5] = [
     (Spell::Missile, MAGIC_MISSILE),
     (Spell::Drain, DRAIN),
     (Spell::Shield, SHIELD),
@@ -48,17 +46,17 @@ const AVAILABLE_SPELLS: [(Spell, i32); 5] = [
 ];
 
 #[derive(Debug, Clone, Copy, Eq)]
-struct State {
-    player_hp: i32,
-    mana: i32,
-    boss_hp: i32,
-    boss_damage: i32,
+pub struct State {
+    pub player_hp: i32,
+    pub mana: i32,
+    pub boss_hp: i32,
+    pub boss_damage: i32,
 
-    timer_shield: i32,
-    timer_poison: i32,
-    timer_recharge: i32,
+    pub timer_shield: i32,
+    pub timer_poison: i32,
+    pub timer_recharge: i32,
 
-    mana_spent: i32,
+    pub mana_spent: i32,
 }
 
 impl PartialEq for State {
@@ -135,7 +133,7 @@ impl State {
     }
 }
 
-fn solve(
+pub fn solve(
     player_hp: i32,
     player_mana: i32,
     boss_hp: i32,
@@ -218,16 +216,3 @@ pub fn part_two(input: &str) -> Option<i32> {
     let boss = parse_input(input);
     solve(50, 500, boss.health, boss.damage, true)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let input = &advent_of_code::template::read_file("examples", DAY);
-        let boss = parse_input(input);
-        let result = solve(10, 250, boss.health, boss.damage, false);
-        assert_eq!(result, Some(250 - 24));
-    }
-}
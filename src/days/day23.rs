@@ -1,53 +1,59 @@
-advent_of_code::solution!(23);
+use crate::mnemonics;
+use crate::utils::ParseError;
+use crate::utils::input::or_panic;
+use crate::utils::lexer::{FromToken, Lexeme};
 
-const NR_REGISTERS: usize = 2;
+pub const NR_REGISTERS: usize = 2;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-enum Instruction {
+pub enum Instruction {
     Hlf(u8),
     Tpl(u8),
     Inc(u8),
     Jmp(i32),
     Jie(u8, i32),
     Jio(u8, i32),
-    NotImplemented,
 }
 
-fn map_register(input: &str) -> u8 {
-    match input {
-        "a" => 0,
-        "b" => 1,
-        _ => panic!(),
+/// The index of register `a` or `b`.
+pub struct Register(pub u8);
+
+impl<'a> FromToken<'a> for Register {
+    fn from_token(line: &'a str, lexeme: &Lexeme<'a>) -> Result<Self, ParseError> {
+        match lexeme.text {
+            "a" => Ok(Register(0)),
+            "b" => Ok(Register(1)),
+            _ => Err(ParseError::new(line, lexeme.text, "register `a` or `b`")),
+        }
     }
 }
 
-fn parse_input(input: &str) -> Vec<Instruction> {
+pub fn parse_instruction(line: &str) -> Result<Instruction, ParseError> {
+    mnemonics!(line, {
+        ["hlf" {r: Register}] => Instruction::Hlf(r.0),
+        ["tpl" {r: Register}] => Instruction::Tpl(r.0),
+        ["inc" {r: Register}] => Instruction::Inc(r.0),
+        ["jmp" {offset: i32}] => Instruction::Jmp(offset),
+        ["jie" {r: Register} "," {offset: i32}] => Instruction::Jie(r.0, offset),
+        ["jio" {r: Register} "," {offset: i32}] => Instruction::Jio(r.0, offset),
+    })
+}
+
+pub fn parse_input(input: &str) -> Vec<Instruction> {
     input
         .lines()
-        .filter(|l| !l.is_empty())
         .map(|l| l.trim())
-        .map(|l| {
-            let cleaned = l.replace(",", "");
-            let parts: Vec<&str> = cleaned.split_whitespace().collect();
-            match parts[0] {
-                "hlf" => Instruction::Hlf(map_register(parts[1])),
-                "tpl" => Instruction::Tpl(map_register(parts[1])),
-                "inc" => Instruction::Inc(map_register(parts[1])),
-                "jmp" => Instruction::Jmp(parts[1].parse::<i32>().unwrap()),
-                "jie" => Instruction::Jie(map_register(parts[1]), parts[2].parse::<i32>().unwrap()),
-                "jio" => Instruction::Jio(map_register(parts[1]), parts[2].parse::<i32>().unwrap()),
-                _ => Instruction::NotImplemented,
-            }
-        })
+        .filter(|l| !l.is_empty())
+        .map(|l| or_panic(parse_instruction(l).map_err(|e| e.within(input, l))))
         .collect()
 }
 
-fn solve(ins: &[Instruction], start_a: i32) -> i32 {
+/// Runs the program until it jumps out of it, returning the registers.
+pub fn run(ins: &[Instruction], start_a: i32) -> [i32; NR_REGISTERS] {
     let mut registers: [i32; NR_REGISTERS] = [0; NR_REGISTERS];
     registers[0] = start_a;
     let mut idx: usize = 0;
-    let mut current = ins[idx];
-    while current != Instruction::NotImplemented {
+    while let Some(&current) = ins.get(idx) {
         match current {
             Instruction::Hlf(r) => {
                 registers[r as usize] /= 2;
@@ -87,17 +93,13 @@ fn solve(ins: &[Instruction], start_a: i32) -> i32 {
                     idx += 1;
                 }
             }
-            Instruction::NotImplemented => {
-                break;
-            }
-        }
-        if idx < ins.len() {
-            current = ins[idx];
-        } else {
-            break;
         }
     }
-    registers[1]
+    registers
+}
+
+pub fn solve(ins: &[Instruction], start_a: i32) -> i32 {
+    run(ins, start_a)[1]
 }
 
 pub fn part_one(input: &str) -> Option<i32> {
@@ -0,0 +1,46 @@
+use crate::utils::input::integer_array;
+
+pub const DIVISOR: usize = 252533;
+pub const MOD: usize = 33554393;
+pub const START_VALUE: usize = 20151125;
+
+pub fn parse_input(input: &str) -> (usize, usize) {
+    let [row, col] = integer_array(input);
+    (row, col)
+}
+
+#[inline(always)]
+pub fn next_val(prev: usize) -> usize {
+    (prev * DIVISOR) % MOD
+}
+
+#[inline(always)]
+pub fn next_idx(row: usize, col: usize) -> (usize, usize) {
+    // diagonally
+    if row == 1 {
+        return (col + 1, 1);
+    }
+    (row.saturating_sub(1), col + 1)
+}
+
+pub fn solve(
+    target_row: usize,
+    target_col: usize,
+    start_row: usize,
+    start_col: usize,
+    start_value: usize,
+) -> usize {
+    debug_assert!(target_row + target_col >= start_row + start_col);
+    let (mut current_row, mut current_col) = (start_row, start_col);
+    let mut current_val = start_value;
+    while (current_row != target_row) || (current_col != target_col) {
+        (current_row, current_col) = next_idx(current_row, current_col);
+        current_val = next_val(current_val);
+    }
+    current_val
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let (target_row, target_col) = parse_input(input);
+    Some(solve(target_row, target_col, 1, 1, START_VALUE))
+}
@@ -1,3 +1,7 @@
+// Lets code generated by `advent_of_code_derive` refer to this crate by name from within it.
+extern crate self as advent_of_code;
+
+pub mod days;
 pub mod template;
 pub mod utils;
 
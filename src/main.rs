@@ -1,81 +1,152 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{all, doctor, download, read, scaffold, solve, time};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{Calendar, Config, Day, Year};
     use std::process;
 
     pub enum AppArguments {
         Download {
+            year: Year,
             day: Day,
         },
         Read {
+            year: Year,
             day: Day,
         },
         Scaffold {
+            year: Year,
             day: Day,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
         },
         Solve {
+            year: Year,
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
         },
         All {
+            year: Year,
             release: bool,
         },
+        Doctor {
+            year: Year,
+        },
         Time {
+            year: Year,
             all: bool,
             day: Option<Day>,
             store: bool,
+            debug: bool,
+            section: Option<String>,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+        },
+    }
+
+    /// Reads the `--year` option, falling back to `year` in `aoc.toml` and then `AOC_YEAR`.
+    /// Needs to be called before parsing free arguments.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        args.opt_value_from_str("--year")?
+            .or_else(|| Config::get().default_year())
+            .ok_or_else(|| {
+                "no year specified, pass `--year`, set `year` in `aoc.toml` or set `AOC_YEAR`."
+                    .into()
+            })
+    }
+
+    /// Checks that a day is part of the event calendar of the given year.
+    fn check_day(year: Year, day: Day) -> Result<Day, Box<dyn std::error::Error>> {
+        let calendar = Calendar::new(year);
+        if calendar.contains(day) {
+            Ok(day)
+        } else {
+            Err(format!(
+                "day {day} is not part of {year}, which has {} days.",
+                calendar.last_day().into_inner()
+            )
+            .into())
+        }
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
+        Config::try_get()?;
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
+                year: parse_year(&mut args)?,
                 release: args.contains("--release"),
             },
+            Some("doctor") => AppArguments::Doctor {
+                year: parse_year(&mut args)?,
+            },
             Some("time") => {
+                let year = parse_year(&mut args)?;
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let debug = args.contains("--debug");
+                let section = args.opt_value_from_str("--section")?;
 
                 AppArguments::Time {
+                    year,
                     all,
-                    day: args.opt_free_from_str()?,
+                    day: args
+                        .opt_free_from_str()?
+                        .map(|day| check_day(year, day))
+                        .transpose()?,
                     store,
+                    debug,
+                    section,
+                }
+            }
+            Some("download") => {
+                let year = parse_year(&mut args)?;
+                AppArguments::Download {
+                    year,
+                    day: check_day(year, args.free_from_str()?)?,
+                }
+            }
+            Some("read") => {
+                let year = parse_year(&mut args)?;
+                AppArguments::Read {
+                    year,
+                    day: check_day(year, args.free_from_str()?)?,
+                }
+            }
+            Some("scaffold") => {
+                let year = parse_year(&mut args)?;
+                let template = args.opt_value_from_str("--template")?;
+                AppArguments::Scaffold {
+                    year,
+                    day: check_day(year, args.free_from_str()?)?,
+                    download: args.contains("--download"),
+                    overwrite: args.contains("--overwrite"),
+                    template,
+                }
+            }
+            Some("solve") => {
+                let year = parse_year(&mut args)?;
+                AppArguments::Solve {
+                    year,
+                    day: check_day(year, args.free_from_str()?)?,
+                    release: args.contains("--release"),
+                    submit: args.opt_value_from_str("--submit")?,
+                    dhat: args.contains("--dhat"),
                 }
             }
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
-            },
-            Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
-            },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
-            },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -102,43 +173,40 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All { year, release } => all::handle(year, release),
+            AppArguments::Doctor { year } => doctor::handle(year),
+            AppArguments::Time {
+                year,
+                day,
+                all,
+                store,
+                debug,
+                section,
+            } => time::handle(year, day, all, store, !debug, section.as_deref()),
+            AppArguments::Download { year, day } => download::handle(year, day),
+            AppArguments::Read { year, day } => read::handle(year, day),
             AppArguments::Scaffold {
+                year,
                 day,
                 download,
                 overwrite,
+                template,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(year, day, overwrite, template.as_deref());
                 if download {
-                    download::handle(day);
+                    download::handle(year, day);
+                    scaffold::generate_parser(year, day);
                 }
             }
             AppArguments::Solve {
+                year,
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+            } => solve::handle(year, day, release, dhat, submit),
             #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
+            AppArguments::Today { wait } => today::handle(wait),
         },
     };
 }
@@ -0,0 +1,182 @@
+use crate::template::{AllDays, Day, Year};
+
+#[cfg(feature = "today")]
+use chrono::{DateTime, Datelike, FixedOffset, TimeZone, Utc};
+
+/// Offset of the Advent of Code server time (EST) to UTC, in hours.
+pub const SERVER_UTC_OFFSET: i32 = -5;
+
+/// From this year on, events only have twelve days.
+const SHORT_CALENDAR_YEAR: u16 = 2025;
+
+/// The advent calendar of an event year: which days exist, when they unlock and how many parts they have.
+///
+/// ```
+/// # use advent_of_code::template::{Calendar, Year};
+/// let calendar = Calendar::new(Year::new(2025).unwrap());
+/// assert_eq!(calendar.last_day().into_inner(), 12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calendar {
+    year: Year,
+}
+
+impl Calendar {
+    pub const fn new(year: Year) -> Self {
+        Self { year }
+    }
+
+    pub fn year(&self) -> Year {
+        self.year
+    }
+
+    /// The final day of the event, which only has a single part.
+    pub fn last_day(&self) -> Day {
+        let last = if self.year.into_inner() >= SHORT_CALENDAR_YEAR {
+            12
+        } else {
+            25
+        };
+        // NOTE: both values are within the valid range of a day.
+        Day::new(last).unwrap()
+    }
+
+    /// Returns `true` if the day is part of this event.
+    pub fn contains(&self, day: Day) -> bool {
+        day <= self.last_day()
+    }
+
+    /// Creates a [`Day`] from the provided value if it's part of this event,
+    /// returns [`None`] otherwise.
+    pub fn day(&self, day: u8) -> Option<Day> {
+        Day::new(day).filter(|day| self.contains(*day))
+    }
+
+    /// An iterator that yields every day of this event.
+    pub fn days(&self) -> AllDays {
+        AllDays::new(self.last_day())
+    }
+
+    /// The number of parts of a day: the final day only has one.
+    pub fn parts(&self, day: Day) -> u8 {
+        if day == self.last_day() { 1 } else { 2 }
+    }
+}
+
+#[cfg(feature = "today")]
+impl Calendar {
+    /// The moment a day's puzzle unlocks: midnight server time on that day of december.
+    pub fn unlock_time(&self, day: Day) -> Option<DateTime<FixedOffset>> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        offset
+            .with_ymd_and_hms(
+                i32::from(self.year.into_inner()),
+                12,
+                u32::from(day.into_inner()),
+                0,
+                0,
+                0,
+            )
+            .single()
+    }
+
+    /// Returns the puzzle of the current day in server time, if `now` falls on a day of an event.
+    pub fn puzzle_at(now: DateTime<Utc>) -> Option<(Year, Day)> {
+        let today = now.with_timezone(&FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?);
+        if today.month() != 12 {
+            return None;
+        }
+        let year = Year::new(u16::try_from(today.year()).ok()?)?;
+        let day = Calendar::new(year).day(u8::try_from(today.day()).ok()?)?;
+        Some((year, day))
+    }
+
+    /// Returns the next puzzle to unlock after `now` and its unlock time.
+    /// Looks into next year's event once this year's calendar is over.
+    pub fn next_unlock(now: DateTime<Utc>) -> Option<(Year, Day, DateTime<FixedOffset>)> {
+        let year = now
+            .with_timezone(&FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?)
+            .year();
+        let year = u16::try_from(year).ok()?;
+
+        [year, year + 1]
+            .into_iter()
+            .filter_map(Year::new)
+            .find_map(|year| {
+                let calendar = Calendar::new(year);
+                calendar.days().find_map(|day| {
+                    let unlock = calendar.unlock_time(day)?;
+                    (unlock > now).then_some((year, day, unlock))
+                })
+            })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Calendar;
+    use crate::{day, template::Year};
+
+    #[test]
+    fn handles_full_calendars() {
+        let calendar = Calendar::new(Year::new(2015).unwrap());
+        assert_eq!(calendar.last_day(), day!(25));
+        assert_eq!(calendar.days().count(), 25);
+        assert_eq!(calendar.parts(day!(24)), 2);
+        assert_eq!(calendar.parts(day!(25)), 1);
+    }
+
+    #[test]
+    fn handles_short_calendars() {
+        let calendar = Calendar::new(Year::new(2025).unwrap());
+        assert_eq!(calendar.last_day(), day!(12));
+        assert_eq!(calendar.days().count(), 12);
+        assert_eq!(calendar.day(12), Some(day!(12)));
+        assert_eq!(calendar.day(13), None);
+        assert_eq!(calendar.parts(day!(12)), 1);
+    }
+
+    #[cfg(feature = "today")]
+    mod today {
+        use chrono::{TimeZone, Utc};
+
+        use super::Calendar;
+        use crate::{day, template::Year};
+
+        #[test]
+        fn finds_puzzle_of_current_server_day() {
+            // 04:59 UTC is still the previous day in server time.
+            let now = Utc.with_ymd_and_hms(2015, 12, 2, 4, 59, 0).unwrap();
+            assert_eq!(
+                Calendar::puzzle_at(now),
+                Some((Year::new(2015).unwrap(), day!(1)))
+            );
+
+            let now = Utc.with_ymd_and_hms(2015, 12, 1, 4, 59, 0).unwrap();
+            assert_eq!(Calendar::puzzle_at(now), None);
+
+            let now = Utc.with_ymd_and_hms(2025, 12, 20, 12, 0, 0).unwrap();
+            assert_eq!(Calendar::puzzle_at(now), None);
+        }
+
+        #[test]
+        fn finds_next_unlock() {
+            let now = Utc.with_ymd_and_hms(2015, 12, 1, 4, 0, 0).unwrap();
+            let (year, day, unlock) = Calendar::next_unlock(now).unwrap();
+            assert_eq!((year.into_inner(), day), (2015, day!(1)));
+            assert_eq!(unlock, Utc.with_ymd_and_hms(2015, 12, 1, 5, 0, 0).unwrap());
+
+            let now = Utc.with_ymd_and_hms(2015, 12, 10, 5, 0, 0).unwrap();
+            let (_, day, _) = Calendar::next_unlock(now).unwrap();
+            assert_eq!(day, day!(11));
+
+            let now = Utc.with_ymd_and_hms(2025, 12, 20, 12, 0, 0).unwrap();
+            let (year, day, _) = Calendar::next_unlock(now).unwrap();
+            assert_eq!((year.into_inner(), day), (2026, day!(1)));
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
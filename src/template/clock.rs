@@ -0,0 +1,24 @@
+/// Module that abstracts over the current time, so time-dependent commands can be tested.
+use std::{thread, time::Duration};
+
+use chrono::{DateTime, Utc};
+
+/// A source of the current time that can also wait.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+    fn sleep(&self, duration: Duration);
+}
+
+/// The system clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
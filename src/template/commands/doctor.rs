@@ -0,0 +1,414 @@
+use std::{
+    env, fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    process::{self, Command},
+};
+
+use crate::template::input_check::{self, InputIssue};
+use crate::template::readme_benchmarks;
+use crate::template::timings::{Timings, get_file_path};
+use crate::template::{Calendar, Config, Day, Year, aoc_cli, get_data_path, get_path_for_bin};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Status {
+    Ok,
+    Warning,
+    Error,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Check {
+    status: Status,
+    message: String,
+}
+
+impl Check {
+    fn ok(message: impl Into<String>) -> Self {
+        Self {
+            status: Status::Ok,
+            message: message.into(),
+        }
+    }
+
+    fn warning(message: impl Into<String>) -> Self {
+        Self {
+            status: Status::Warning,
+            message: message.into(),
+        }
+    }
+
+    fn error(message: impl Into<String>) -> Self {
+        Self {
+            status: Status::Error,
+            message: message.into(),
+        }
+    }
+}
+
+pub fn handle(year: Year) {
+    println!("🩺 Checking the project setup for {year}...");
+    println!();
+
+    let checks: Vec<Check> = [
+        check_aoc_cli(),
+        check_year(env::var("AOC_YEAR").ok().as_deref(), Config::get().year),
+        check_toolchain(),
+        check_data(year),
+        check_readme(year),
+        check_timings(year),
+    ]
+    .concat();
+
+    for check in &checks {
+        let icon = match check.status {
+            Status::Ok => "✅",
+            Status::Warning => "⚠️ ",
+            Status::Error => "❌",
+        };
+        println!("{icon} {}", check.message);
+    }
+
+    let count = |status| checks.iter().filter(|c| c.status == status).count();
+    println!("---");
+    println!(
+        "🎄 {} warning(s), {} error(s).",
+        count(Status::Warning),
+        count(Status::Error)
+    );
+
+    if count(Status::Error) > 0 {
+        process::exit(1);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn check_aoc_cli() -> Vec<Check> {
+    let cli = match aoc_cli::check() {
+        Ok(()) => Check::ok("`aoc` is callable."),
+        Err(_) => Check::error(
+            "`aoc` is not callable, install it with `cargo install aoc-cli` to download and submit.",
+        ),
+    };
+
+    let session = match find_session() {
+        Some(source) => Check::ok(format!("found a session token in {source}.")),
+        None => Check::warning(
+            "no session token found, set `ADVENT_OF_CODE_SESSION` or create `~/.adventofcode.session`.",
+        ),
+    };
+
+    vec![cli, session]
+}
+
+/// Looks for the session token in the places `aoc-cli` reads it from.
+fn find_session() -> Option<String> {
+    if env::var("ADVENT_OF_CODE_SESSION").is_ok_and(|s| !s.trim().is_empty()) {
+        return Some("`ADVENT_OF_CODE_SESSION`".into());
+    }
+
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from);
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .or_else(|| env::var_os("APPDATA"))
+        .map(PathBuf::from)
+        .or_else(|| home.as_ref().map(|home| home.join(".config")));
+
+    [
+        home.map(|home| home.join(".adventofcode.session")),
+        config.map(|config| config.join("adventofcode.session")),
+    ]
+    .into_iter()
+    .flatten()
+    .find(|path| fs::read_to_string(path).is_ok_and(|s| !s.trim().is_empty()))
+    .map(|path| format!("\"{}\"", path.display()))
+}
+
+/// Checks that `AOC_YEAR` is valid and agrees with `year` in `aoc.toml`.
+/// Binaries without a year prefix are compiled for `AOC_YEAR`, while the CLI prefers `aoc.toml`.
+fn check_year(env_year: Option<&str>, config_year: Option<Year>) -> Vec<Check> {
+    let env_year = match env_year.map(str::parse::<Year>) {
+        None => {
+            return vec![match config_year {
+                Some(year) => Check::ok(format!("default year is {year}, set in `aoc.toml`.")),
+                None => Check::warning(
+                    "no default year, set `AOC_YEAR` in `.cargo/config.toml` or `year` in `aoc.toml`.",
+                ),
+            }];
+        }
+        Some(Err(e)) => return vec![Check::error(format!("`AOC_YEAR` is invalid: {e}."))],
+        Some(Ok(year)) => year,
+    };
+
+    let mut checks = vec![];
+    match config_year {
+        Some(year) if year != env_year => checks.push(Check::warning(format!(
+            "`year` in `aoc.toml` ({year}) differs from `AOC_YEAR` ({env_year}), \
+            solutions without a year in their name are compiled for {env_year}."
+        ))),
+        _ => checks.push(Check::ok(format!("default year is {env_year}."))),
+    }
+
+    let legacy_bins = legacy_bins();
+    if !legacy_bins.is_empty() {
+        checks.push(Check::warning(format!(
+            "solutions without a year in their name rely on `AOC_YEAR`, rename them to `YYYY_DD`: {}.",
+            legacy_bins.join(", ")
+        )));
+    }
+
+    checks
+}
+
+/// Returns the solution binaries that are not named `YYYY_DD`.
+fn legacy_bins() -> Vec<String> {
+    let Ok(entries) = fs::read_dir(&Config::get().bin_dir) else {
+        return vec![];
+    };
+
+    let mut names: Vec<String> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+        .filter(|name| Year::from_bin_name(name).is_none())
+        .collect();
+    names.sort();
+    names
+}
+
+fn check_toolchain() -> Vec<Check> {
+    let version = Command::new("rustc")
+        .arg("--version")
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_default();
+
+    vec![toolchain_check(
+        &version,
+        env::var_os("RUSTC_BOOTSTRAP").is_some(),
+        &feature_gated_bins(),
+    )]
+}
+
+/// Solutions using `#![feature(...)]`, such as `int_roundings`, only build on nightly.
+fn toolchain_check(version: &str, bootstrap: bool, feature_gated: &[String]) -> Check {
+    if version.is_empty() {
+        return Check::error("could not call `rustc`.");
+    }
+    if feature_gated.is_empty() || version.contains("nightly") || bootstrap {
+        return Check::ok(format!("toolchain {version}."));
+    }
+    Check::error(format!(
+        "{} use(s) unstable features but the toolchain is {version}, \
+        run `rustup override set nightly` to build them.",
+        feature_gated.join(", ")
+    ))
+}
+
+/// Returns the solution binaries that enable unstable features.
+fn feature_gated_bins() -> Vec<String> {
+    let Ok(entries) = fs::read_dir(&Config::get().bin_dir) else {
+        return vec![];
+    };
+
+    let mut paths: Vec<String> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| fs::read_to_string(path).is_ok_and(|s| s.contains("#![feature(")))
+        .map(|path| path.display().to_string())
+        .collect();
+    paths.sort();
+    paths
+}
+
+/// Checks the inputs and examples of every scaffolded day.
+fn check_data(year: Year) -> Vec<Check> {
+    let days: Vec<Day> = Calendar::new(year)
+        .days()
+        .filter(|day| Path::new(&get_path_for_bin(year, *day)).exists())
+        .collect();
+
+    if days.is_empty() {
+        return vec![Check::warning(format!(
+            "no solutions scaffolded for {year} yet, run `cargo scaffold <day> --year {year}`."
+        ))];
+    }
+
+    let mut checks: Vec<Check> = days
+        .iter()
+        .flat_map(|day| {
+            let input = get_data_path("inputs", year, &format!("{day}.txt"));
+            let download = format!("run `cargo download {} --year {year}`.", day.into_inner());
+
+            // days with different examples per part may only have `01-1.txt` and `01-2.txt`.
+            let example = get_data_path("examples", year, &format!("{day}.txt"));
+            let example_parts: Vec<String> = (1..=2)
+                .map(|part| get_data_path("examples", year, &format!("{day}-{part}.txt")))
+                .filter(|path| Path::new(path).exists())
+                .collect();
+            let examples = if Path::new(&example).exists() || example_parts.is_empty() {
+                vec![example]
+            } else {
+                example_parts
+            };
+
+            let mut checks = vec![data_file_check(
+                &input,
+                fs::read_to_string(&input).ok(),
+                &download,
+                true,
+            )];
+            checks.extend(examples.iter().map(|example| {
+                data_file_check(
+                    example,
+                    fs::read_to_string(example).ok(),
+                    "paste the example from the puzzle description.",
+                    false,
+                )
+            }));
+            checks
+        })
+        .flatten()
+        .collect();
+
+    if checks.is_empty() {
+        checks.push(Check::ok(format!(
+            "inputs and examples of {} scaffolded day(s) are present.",
+            days.len()
+        )));
+    }
+
+    checks
+}
+
+/// Checks a single data file, returns `None` if there is nothing to report.
+fn data_file_check(
+    path: &str,
+    contents: Option<String>,
+    hint: &str,
+    is_input: bool,
+) -> Option<Check> {
+    let Some(contents) = contents else {
+        return Some(Check::error(format!("\"{path}\" is missing, {hint}")));
+    };
+
+    let (_, issues) = input_check::normalize(contents);
+    let issue = issues.first()?;
+    let message = match issue {
+        InputIssue::Empty => format!("\"{path}\" {issue}, {hint}"),
+        _ => format!("\"{path}\" {issue} when read."),
+    };
+
+    Some(if *issue == InputIssue::Empty && is_input {
+        Check::error(message)
+    } else {
+        Check::warning(message)
+    })
+}
+
+fn check_readme(year: Year) -> Vec<Check> {
+    let path = &Config::get().readme;
+    let section = readme_benchmarks::default_section(year);
+
+    let Ok(readme) = fs::read_to_string(path) else {
+        return vec![Check::error(format!("could not read \"{path}\"."))];
+    };
+
+    vec![
+        match readme_benchmarks::check_markers(&readme, section.as_deref()) {
+            Ok(()) => Check::ok(format!("\"{path}\" has the benchmark table markers.")),
+            Err(e) => Check::error(format!("\"{path}\": {e}")),
+        },
+    ]
+}
+
+fn check_timings(year: Year) -> Vec<Check> {
+    let section = readme_benchmarks::default_section(year);
+    let path = get_file_path(year, section.as_deref());
+
+    vec![match fs::read_to_string(&path) {
+        Ok(contents) => match Timings::try_from(contents) {
+            Ok(timings) => Check::ok(format!(
+                "\"{path}\" holds timings of {} day(s).",
+                timings.data.len()
+            )),
+            Err(e) => Check::error(format!(
+                "\"{path}\" could not be parsed: {e}. Remove it and run `cargo time --all --store`."
+            )),
+        },
+        Err(e) if e.kind() == ErrorKind::NotFound => Check::ok(format!(
+            "no timings stored for {year} yet, `cargo time --store` creates \"{path}\"."
+        )),
+        Err(e) => Check::error(format!("could not read \"{path}\": {e}.")),
+    }]
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Status, check_year, data_file_check, toolchain_check};
+    use crate::template::Year;
+
+    #[test]
+    fn checks_year_consistency() {
+        let year = Year::new(2016);
+
+        assert_eq!(check_year(Some("2016"), year)[0].status, Status::Ok);
+        assert_eq!(check_year(None, year)[0].status, Status::Ok);
+        assert_eq!(check_year(None, None)[0].status, Status::Warning);
+        assert_eq!(check_year(Some("2014"), year)[0].status, Status::Error);
+
+        let checks = check_year(Some("2015"), year);
+        assert_eq!(checks[0].status, Status::Warning);
+        assert!(checks[0].message.contains("differs from `AOC_YEAR` (2015)"));
+    }
+
+    #[test]
+    fn checks_toolchain() {
+        let gated = ["./src/bin/2015_21.rs".to_string()];
+        let stable = "rustc 1.90.0 (1159e78c4 2025-09-14)";
+
+        assert_eq!(toolchain_check(stable, false, &[]).status, Status::Ok);
+        assert_eq!(
+            toolchain_check("rustc 1.92.0-nightly", false, &gated).status,
+            Status::Ok
+        );
+        assert_eq!(toolchain_check(stable, true, &gated).status, Status::Ok);
+
+        let check = toolchain_check(stable, false, &gated);
+        assert_eq!(check.status, Status::Error);
+        assert!(check.message.contains("2015_21.rs"));
+        assert_eq!(toolchain_check("", false, &[]).status, Status::Error);
+    }
+
+    #[test]
+    fn checks_data_files() {
+        let hint = "run `cargo download 1`.";
+
+        assert_eq!(
+            data_file_check("a.txt", Some("1\n".into()), hint, true),
+            None
+        );
+
+        let missing = data_file_check("a.txt", None, hint, false).unwrap();
+        assert_eq!(missing.status, Status::Error);
+        assert!(missing.message.contains("is missing"));
+
+        let empty = data_file_check("a.txt", Some(String::new()), hint, true).unwrap();
+        assert_eq!(empty.status, Status::Error);
+        assert!(empty.message.ends_with(hint));
+
+        let empty_example = data_file_check("a.txt", Some(String::new()), hint, false).unwrap();
+        assert_eq!(empty_example.status, Status::Warning);
+
+        let crlf = data_file_check("a.txt", Some("1\r\n".into()), hint, true).unwrap();
+        assert_eq!(crlf.status, Status::Warning);
+        assert!(crlf.message.contains("CRLF"));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
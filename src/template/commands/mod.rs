@@ -1,6 +1,9 @@
 pub mod all;
+pub mod doctor;
 pub mod download;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
@@ -1,14 +1,76 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::{Config, Day, Year, get_data_path, get_path_for_bin, input_shape};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
+/// Built-in templates for common puzzle shapes, selectable via `--template <name>`.
+const TEMPLATES: [(&str, &str); 4] = [
+    ("grid", include_str!("../templates/grid.txt")),
+    ("graph", include_str!("../templates/graph.txt")),
+    (
+        "instructions",
+        include_str!("../templates/instructions.txt"),
+    ),
+    ("numbers", include_str!("../templates/numbers.txt")),
+];
+
+/// Returns the module template to scaffold a day from.
+/// A named template is looked up in `templates_dir` first, so user templates can replace built-in ones.
+/// Without a name, the `template` file from `aoc.toml` or the default template is used.
+fn module_template(name: Option<&str>) -> Result<String, String> {
+    let config = Config::get();
+
+    let Some(name) = name else {
+        return match &config.template {
+            Some(path) => fs::read_to_string(path)
+                .map_err(|e| format!("Failed to read template file \"{path}\": {e}")),
+            None => Ok(MODULE_TEMPLATE.into()),
+        };
+    };
+
+    let path = Path::new(&config.templates_dir).join(format!("{name}.txt"));
+    if path.is_file() {
+        return fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read template file \"{}\": {e}", path.display()));
+    }
+
+    TEMPLATES
+        .iter()
+        .find(|(template, _)| *template == name)
+        .map(|(_, contents)| (*contents).to_string())
+        .ok_or_else(|| {
+            format!(
+                "Unknown template `{name}`, expected one of: {}.",
+                available_templates(&config.templates_dir).join(", ")
+            )
+        })
+}
+
+/// Lists the names of the built-in templates and the `.txt` files in the user's template folder.
+fn available_templates(templates_dir: &str) -> Vec<String> {
+    let mut names: Vec<String> = TEMPLATES.iter().map(|(name, _)| name.to_string()).collect();
+
+    if let Ok(entries) = fs::read_dir(templates_dir) {
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.extension().is_some_and(|ext| ext == "txt")
+                && let Some(name) = path.file_stem().and_then(|s| s.to_str())
+                && !names.iter().any(|n| n == name)
+            {
+                names.push(name.to_string());
+            }
+        }
+    }
+
+    names
+}
+
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
@@ -27,10 +89,27 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(year: Year, day: Day, overwrite: bool, template: Option<&str>) {
+    let template = match module_template(template) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let input_path = get_data_path("inputs", year, &format!("{day}.txt"));
+    let example_path = get_data_path("examples", year, &format!("{day}.txt"));
+    let module_path = get_path_for_bin(year, day);
+
+    for path in [&input_path, &example_path] {
+        if let Some(parent) = Path::new(path).parent()
+            && let Err(e) = fs::create_dir_all(parent)
+        {
+            eprintln!("Failed to create data folder: {e}");
+            process::exit(1);
+        }
+    }
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -41,7 +120,7 @@ pub fn handle(day: Day, overwrite: bool) {
     };
 
     match file.write_all(
-        MODULE_TEMPLATE
+        template
             .replace("%DAY_NUMBER%", &day.into_inner().to_string())
             .as_bytes(),
     ) {
@@ -74,6 +153,82 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
+    // templates that test against part-specific examples get an empty `01-2.txt` as well.
+    if template.contains("read_file_part") {
+        let example_part_path = get_data_path("examples", year, &format!("{day}-2.txt"));
+        match create_file(&example_part_path) {
+            Ok(_) => {
+                println!("Created empty example file \"{}\"", &example_part_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
+}
+
+/// Inserts a `parse_input` stub matching the shape of the downloaded input into a day's module.
+/// Does nothing if the input is empty or the module already defines `parse_input`.
+pub fn generate_parser(year: Year, day: Day) {
+    let input_path = get_data_path("inputs", year, &format!("{day}.txt"));
+    let module_path = get_path_for_bin(year, day);
+
+    let Ok(input) = fs::read_to_string(&input_path) else {
+        return;
+    };
+    if input.trim().is_empty() {
+        return;
+    }
+
+    let module = match fs::read_to_string(&module_path) {
+        Ok(module) => module,
+        Err(e) => {
+            eprintln!("Failed to read module file: {e}");
+            process::exit(1);
+        }
+    };
+
+    let shape = input_shape::detect(&input);
+    let Some(module) = input_shape::insert_parser(&module, &shape.parser()) else {
+        return;
+    };
+
+    match fs::write(&module_path, module) {
+        Ok(()) => {
+            println!(
+                "Generated `parse_input` for {} in \"{}\"",
+                shape.describe(),
+                &module_path
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to write parser: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{TEMPLATES, module_template};
+
+    #[test]
+    fn resolves_built_in_templates() {
+        for (name, contents) in TEMPLATES {
+            assert_eq!(module_template(Some(name)).unwrap(), contents);
+            assert!(contents.contains("fn parse_input"));
+            assert!(contents.contains("%DAY_NUMBER%"));
+        }
+    }
+
+    #[test]
+    fn errors_for_unknown_templates() {
+        let err = module_template(Some("maze")).unwrap_err();
+        assert!(err.starts_with("Unknown template `maze`"));
+        assert!(err.contains("grid, graph, instructions, numbers"));
+    }
 }
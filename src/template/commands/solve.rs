@@ -1,9 +1,13 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{Day, Year, get_bin_name};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(year: Year, day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        get_bin_name(year, day),
+    ];
 
     if dhat {
         cmd_args.extend([
@@ -1,39 +1,62 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, process};
 
+use crate::template::readme_layout::TableLayout;
 use crate::template::run_multi::run_multi;
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{Day, Year, all_days, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+pub fn handle(
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    is_release: bool,
+    section: Option<&str>,
+) {
+    // fail before benchmarking if the readme table could not be written afterwards.
+    if store && let Err(e) = TableLayout::from_env() {
+        eprintln!("Invalid benchmark table layout: {e}");
+        process::exit(1);
+    }
+
+    if let Some(Err(e)) = section.map(readme_benchmarks::validate_section) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+
+    // years other than the default get their own readme section unless specified otherwise.
+    let year_section = readme_benchmarks::default_section(year);
+    let section = section.or(year_section.as_deref());
+
+    let stored_timings = Timings::read_from_file(year, section);
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                all_days(year).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                all_days(year)
+                    .filter(|day| !stored_timings.is_day_complete(year, *day))
                     .collect()
             }
         },
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = run_multi(year, &days_to_run, is_release, true).unwrap();
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year, section).unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings, section) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+            Err(e) => {
+                eprintln!("Failed to store updated benchmarks: {e}");
             }
         }
     }
@@ -0,0 +1,237 @@
+use std::{
+    io::{Write, stdout},
+    process,
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+
+use crate::template::commands::{read, scaffold};
+use crate::template::{Calendar, Clock, Day, SystemClock, Year, aoc_cli};
+
+/// The number of download attempts once a puzzle unlocked, its inputs are not always available right away.
+const DOWNLOAD_ATTEMPTS: u32 = 6;
+const INITIAL_BACKOFF: Duration = Duration::from_secs(2);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+pub fn handle(wait: bool) {
+    handle_with(&SystemClock, wait);
+}
+
+fn handle_with(clock: &impl Clock, wait: bool) {
+    let (year, day) = match Calendar::puzzle_at(clock.now()) {
+        Some(puzzle) => puzzle,
+        None if wait => wait_for_next_unlock(clock),
+        None => {
+            eprintln!(
+                "`today` command can only be run on a day of this year's advent \
+                calendar. Please use `scaffold` with a specific day or wait for the next \
+                puzzle with `cargo today --wait`."
+            );
+            process::exit(1)
+        }
+    };
+
+    scaffold::handle(year, day, false, None);
+
+    if aoc_cli::check().is_err() {
+        eprintln!(
+            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+        );
+        process::exit(1);
+    }
+
+    // when waiting, the download races the unlock and is retried a few times.
+    let attempts = if wait { DOWNLOAD_ATTEMPTS } else { 1 };
+    if let Err(e) = retry_with_backoff(clock, attempts, || aoc_cli::download(year, day)) {
+        eprintln!("failed to call aoc-cli: {e}");
+        process::exit(1);
+    }
+
+    scaffold::generate_parser(year, day);
+    read::handle(year, day);
+}
+
+/// Shows a countdown to the next puzzle unlock and returns that puzzle once it is available.
+fn wait_for_next_unlock(clock: &impl Clock) -> (Year, Day) {
+    let Some((year, day, unlock)) = Calendar::next_unlock(clock.now()) else {
+        eprintln!("could not determine the next puzzle unlock.");
+        process::exit(1);
+    };
+
+    let mut stdout = stdout();
+    wait_until(clock, unlock.with_timezone(&Utc), |remaining| {
+        print!(
+            "\r⏳ Day {} of {year} unlocks in {}",
+            day.into_inner(),
+            format_countdown(remaining)
+        );
+        let _ = stdout.flush();
+    });
+    println!(
+        "\r🎄 Day {} of {year} is unlocked!          ",
+        day.into_inner()
+    );
+
+    (year, day)
+}
+
+/// Sleeps until `target`, calling `on_tick` with the remaining time about once a second.
+fn wait_until(clock: &impl Clock, target: DateTime<Utc>, mut on_tick: impl FnMut(Duration)) {
+    while let Ok(remaining) = (target - clock.now()).to_std() {
+        if remaining.is_zero() {
+            break;
+        }
+        on_tick(remaining);
+        // sleep to the next full second, so the countdown ticks in step with the clock.
+        match remaining.subsec_nanos() {
+            0 => clock.sleep(Duration::from_secs(1)),
+            nanos => clock.sleep(Duration::from_nanos(u64::from(nanos))),
+        }
+    }
+}
+
+/// Calls `f` up to `attempts` times, doubling the delay between attempts up to [`MAX_BACKOFF`].
+fn retry_with_backoff<T, E: std::fmt::Display>(
+    clock: &impl Clock,
+    attempts: u32,
+    mut f: impl FnMut() -> Result<T, E>,
+) -> Result<T, E> {
+    let mut delay = INITIAL_BACKOFF;
+    let mut attempt = 1;
+
+    loop {
+        match f() {
+            Ok(result) => return Ok(result),
+            Err(e) if attempt >= attempts => return Err(e),
+            Err(e) => {
+                eprintln!("{e} Retrying in {}s...", delay.as_secs());
+                clock.sleep(delay);
+                delay = (delay * 2).min(MAX_BACKOFF);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Formats a duration as `HH:MM:SS`, prefixed with the number of days if longer than one.
+fn format_countdown(duration: Duration) -> String {
+    // round up, so the countdown shows `00:00:01` during the last second.
+    let secs = duration.as_secs() + u64::from(duration.subsec_nanos() > 0);
+    let (days, hours, minutes, seconds) =
+        (secs / 86_400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{cell::RefCell, time::Duration};
+
+    use chrono::{DateTime, TimeZone, Utc};
+
+    use super::{format_countdown, retry_with_backoff, wait_until};
+    use crate::template::Clock;
+
+    /// A clock that only advances when sleeping and records every sleep.
+    struct MockClock {
+        now: RefCell<DateTime<Utc>>,
+        sleeps: RefCell<Vec<Duration>>,
+    }
+
+    impl MockClock {
+        fn new(now: DateTime<Utc>) -> Self {
+            Self {
+                now: RefCell::new(now),
+                sleeps: RefCell::new(vec![]),
+            }
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> DateTime<Utc> {
+            *self.now.borrow()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            *self.now.borrow_mut() += duration;
+            self.sleeps.borrow_mut().push(duration);
+        }
+    }
+
+    #[test]
+    fn counts_down_to_target() {
+        let start = Utc.with_ymd_and_hms(2025, 12, 1, 4, 59, 57).unwrap();
+        let clock = MockClock::new(start + Duration::from_millis(500));
+        let target = Utc.with_ymd_and_hms(2025, 12, 1, 5, 0, 0).unwrap();
+
+        let mut ticks = vec![];
+        wait_until(&clock, target, |remaining| {
+            ticks.push(format_countdown(remaining))
+        });
+
+        assert_eq!(clock.now(), target);
+        assert_eq!(ticks, vec!["00:00:03", "00:00:02", "00:00:01"]);
+        assert_eq!(clock.sleeps.borrow()[0], Duration::from_millis(500));
+    }
+
+    #[test]
+    fn does_not_wait_for_past_targets() {
+        let target = Utc.with_ymd_and_hms(2025, 12, 1, 5, 0, 0).unwrap();
+        let clock = MockClock::new(target + Duration::from_secs(1));
+
+        wait_until(&clock, target, |_| panic!("should not tick"));
+        assert!(clock.sleeps.borrow().is_empty());
+    }
+
+    #[test]
+    fn retries_with_backoff() {
+        let clock = MockClock::new(Utc.with_ymd_and_hms(2025, 12, 1, 5, 0, 0).unwrap());
+        let mut calls = 0;
+
+        let result = retry_with_backoff(&clock, 6, || {
+            calls += 1;
+            if calls < 4 {
+                Err("not yet.")
+            } else {
+                Ok(calls)
+            }
+        });
+
+        assert_eq!(result, Ok(4));
+        assert_eq!(
+            *clock.sleeps.borrow(),
+            [2, 4, 8].map(Duration::from_secs).to_vec()
+        );
+    }
+
+    #[test]
+    fn gives_up_after_attempts() {
+        let clock = MockClock::new(Utc.with_ymd_and_hms(2025, 12, 1, 5, 0, 0).unwrap());
+        let result: Result<(), _> = retry_with_backoff(&clock, 8, || Err("unavailable."));
+
+        assert_eq!(result, Err("unavailable."));
+        assert_eq!(clock.sleeps.borrow().len(), 7);
+        assert_eq!(
+            *clock.sleeps.borrow().last().unwrap(),
+            Duration::from_secs(60)
+        );
+    }
+
+    #[test]
+    fn formats_countdowns() {
+        assert_eq!(format_countdown(Duration::from_millis(59_200)), "00:01:00");
+        assert_eq!(
+            format_countdown(Duration::from_secs(3 * 86_400 + 3661)),
+            "3d 01:01:01"
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
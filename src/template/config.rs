@@ -0,0 +1,246 @@
+/// Module that reads the optional `aoc.toml` config file in the project root.
+/// The file is read once per process, every key is optional and falls back to its default:
+///  - `year`: the default year of all commands, takes precedence over `AOC_YEAR`.
+///  - `data_dir`: the folder holding inputs, examples, puzzles and timings per year (`data`).
+///  - `bin_dir`: the folder holding the solution binaries (`src/bin`).
+///  - `readme`: the readme that benchmarks and progress are written to (`README.md`).
+///  - `chart`: the benchmark chart linked from the readme, named sections add a suffix (`.assets/benchmarks.svg`).
+///  - `template`: a file to scaffold new days from, instead of the built-in `src/template.txt`.
+///  - `templates_dir`: the folder holding user templates selectable via `--template` (`templates`).
+///  - `bench_budget_ms`: the approximate time spent benchmarking a part in milliseconds (`1000`).
+///
+/// Only a flat subset of TOML is supported: `key = value` lines with strings or integers and `#` comments.
+use std::{fs, io, sync::OnceLock, time::Duration};
+
+use crate::template::Year;
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+const KEYS: [&str; 8] = [
+    "year",
+    "data_dir",
+    "bin_dir",
+    "readme",
+    "chart",
+    "template",
+    "templates_dir",
+    "bench_budget_ms",
+];
+
+static CONFIG: OnceLock<Result<Config, String>> = OnceLock::new();
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    pub year: Option<Year>,
+    pub data_dir: String,
+    pub bin_dir: String,
+    pub readme: String,
+    pub chart: String,
+    pub template: Option<String>,
+    pub templates_dir: String,
+    pub bench_budget: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            year: None,
+            data_dir: "data".into(),
+            bin_dir: "src/bin".into(),
+            readme: "README.md".into(),
+            chart: ".assets/benchmarks.svg".into(),
+            template: None,
+            templates_dir: "templates".into(),
+            bench_budget: Duration::from_secs(1),
+        }
+    }
+}
+
+impl Config {
+    /// Returns the config of this project, reading `aoc.toml` on first use.
+    pub fn try_get() -> Result<&'static Config, String> {
+        CONFIG
+            .get_or_init(|| match fs::read_to_string(CONFIG_FILE) {
+                Ok(s) => Config::parse(&s).map_err(|e| format!("{CONFIG_FILE}: {e}")),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+                Err(e) => Err(format!("{CONFIG_FILE}: could not read file: {e}")),
+            })
+            .as_ref()
+            .map_err(Clone::clone)
+    }
+
+    /// Returns the config of this project.
+    ///
+    /// # Panics
+    /// Panics if `aoc.toml` is invalid. The CLI validates the file on startup, see [`Config::try_get`].
+    pub fn get() -> &'static Config {
+        Self::try_get().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// The year commands default to: `year` from `aoc.toml`, falling back to `AOC_YEAR`.
+    pub fn default_year(&self) -> Option<Year> {
+        self.year.or_else(Year::from_env)
+    }
+
+    /// Parses the contents of a config file, keys that are not present keep their default.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut config = Config::default();
+
+        for (i, line) in s.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {line_number}: expected `key = value`."))?;
+            let key = key.trim();
+            let value = parse_value(value)
+                .ok_or_else(|| format!("line {line_number}: invalid value for `{key}`."))?;
+
+            let expected = |kind: &str| format!("line {line_number}: expected {kind} for `{key}`.");
+
+            match (key, value) {
+                ("year", Value::Integer(year)) => {
+                    config.year = Some(
+                        u16::try_from(year)
+                            .ok()
+                            .and_then(Year::new)
+                            .ok_or_else(|| expected("a year of 2015 or later"))?,
+                    );
+                }
+                ("data_dir", Value::String(s)) => config.data_dir = s,
+                ("bin_dir", Value::String(s)) => config.bin_dir = s,
+                ("readme", Value::String(s)) => config.readme = s,
+                ("chart", Value::String(s)) => config.chart = s,
+                ("template", Value::String(s)) => config.template = Some(s),
+                ("templates_dir", Value::String(s)) => config.templates_dir = s,
+                ("bench_budget_ms", Value::Integer(ms)) => {
+                    config.bench_budget = Duration::from_millis(
+                        u64::try_from(ms).map_err(|_| expected("a positive integer"))?,
+                    );
+                }
+                ("year" | "bench_budget_ms", _) => return Err(expected("an integer")),
+                ("data_dir" | "bin_dir" | "readme" | "chart" | "template" | "templates_dir", _) => {
+                    return Err(expected("a string"));
+                }
+                (key, _) => {
+                    return Err(format!(
+                        "line {line_number}: unknown key `{key}`, expected one of: {}.",
+                        KEYS.join(", ")
+                    ));
+                }
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+enum Value {
+    String(String),
+    Integer(i64),
+}
+
+/// Parses a value followed by an optional comment.
+fn parse_value(s: &str) -> Option<Value> {
+    let s = s.trim();
+
+    if let Some(rest) = s.strip_prefix('"') {
+        let mut value = String::new();
+        let mut chars = rest.chars();
+        loop {
+            match chars.next()? {
+                '"' => break,
+                '\\' => match chars.next()? {
+                    '"' => value.push('"'),
+                    '\\' => value.push('\\'),
+                    _ => return None,
+                },
+                c => value.push(c),
+            }
+        }
+        let trailing = chars.as_str().trim();
+        return (trailing.is_empty() || trailing.starts_with('#')).then_some(Value::String(value));
+    }
+
+    let value = s.split('#').next()?.trim().replace('_', "");
+    value.parse().ok().map(Value::Integer)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::Config;
+    use crate::template::Year;
+
+    #[test]
+    fn falls_back_to_defaults() {
+        let config = Config::parse("# nothing configured\n\n").unwrap();
+        assert_eq!(config, Config::default());
+        assert_eq!(config.data_dir, "data");
+        assert_eq!(config.bench_budget, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn parses_values() {
+        let config = Config::parse(
+            r#"
+            year = 2016
+            data_dir = "puzzles/data" # inputs live here
+            bin_dir = "src/bin"
+            readme = "docs/README.md"
+            chart = "docs/benchmarks.svg"
+            template = "templates/day.rs"
+            bench_budget_ms = 2_500
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Year::new(2016));
+        assert_eq!(config.data_dir, "puzzles/data");
+        assert_eq!(config.readme, "docs/README.md");
+        assert_eq!(config.chart, "docs/benchmarks.svg");
+        assert_eq!(config.template.as_deref(), Some("templates/day.rs"));
+        assert_eq!(config.bench_budget, Duration::from_millis(2500));
+    }
+
+    #[test]
+    fn reports_unknown_keys() {
+        let err = Config::parse("year = 2016\ninputs = \"in\"").unwrap_err();
+        assert!(err.starts_with("line 2: unknown key `inputs`"));
+        assert!(err.contains("data_dir"));
+    }
+
+    #[test]
+    fn reports_invalid_values() {
+        assert!(
+            Config::parse("year = \"2016\"")
+                .unwrap_err()
+                .contains("expected an integer")
+        );
+        assert!(
+            Config::parse("year = 2014")
+                .unwrap_err()
+                .contains("2015 or later")
+        );
+        assert!(
+            Config::parse("readme = \"README.md")
+                .unwrap_err()
+                .contains("invalid value")
+        );
+        assert!(
+            Config::parse("readme")
+                .unwrap_err()
+                .contains("expected `key = value`")
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,100 @@
+/// Module that validates and normalizes data files before they are handed to a solution.
+/// Solutions can rely on `\n` line endings and a trailing newline, regardless of how the file was created.
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputIssue {
+    Empty,
+    ByteOrderMark,
+    CarriageReturns,
+    MissingTrailingNewline,
+}
+
+impl Display for InputIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputIssue::Empty => write!(f, "is empty"),
+            InputIssue::ByteOrderMark => {
+                write!(f, "starts with a byte order mark, which was removed")
+            }
+            InputIssue::CarriageReturns => {
+                write!(
+                    f,
+                    "uses windows line endings (CRLF), which were converted to `\\n`"
+                )
+            }
+            InputIssue::MissingTrailingNewline => {
+                write!(f, "does not end with a newline, which was added")
+            }
+        }
+    }
+}
+
+/// Normalizes the contents of a data file and returns the issues that were found.
+pub fn normalize(mut contents: String) -> (String, Vec<InputIssue>) {
+    let mut issues = vec![];
+
+    if let Some(stripped) = contents.strip_prefix('\u{feff}') {
+        contents = stripped.to_string();
+        issues.push(InputIssue::ByteOrderMark);
+    }
+
+    if contents.contains('\r') {
+        contents = contents.replace("\r\n", "\n").replace('\r', "\n");
+        issues.push(InputIssue::CarriageReturns);
+    }
+
+    if contents.trim().is_empty() {
+        issues.push(InputIssue::Empty);
+    } else if !contents.ends_with('\n') {
+        contents.push('\n');
+        issues.push(InputIssue::MissingTrailingNewline);
+    }
+
+    (contents, issues)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{InputIssue, normalize};
+
+    #[test]
+    fn keeps_valid_inputs() {
+        let (contents, issues) = normalize("a\nb\n".into());
+        assert_eq!(contents, "a\nb\n");
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn normalizes_line_endings() {
+        let (contents, issues) = normalize("a\r\n\r\nb\r\n".into());
+        assert_eq!(contents, "a\n\nb\n");
+        assert_eq!(issues, vec![InputIssue::CarriageReturns]);
+    }
+
+    #[test]
+    fn removes_byte_order_mark_and_adds_newline() {
+        let (contents, issues) = normalize("\u{feff}1,2,3".into());
+        assert_eq!(contents, "1,2,3\n");
+        assert_eq!(
+            issues,
+            vec![
+                InputIssue::ByteOrderMark,
+                InputIssue::MissingTrailingNewline
+            ]
+        );
+    }
+
+    #[test]
+    fn detects_empty_inputs() {
+        assert_eq!(normalize(String::new()).1, vec![InputIssue::Empty]);
+        assert_eq!(
+            normalize("\r\n".into()).1,
+            vec![InputIssue::CarriageReturns, InputIssue::Empty]
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,539 @@
+/// Module that sniffs the shape of a puzzle input and generates a matching `parse_input` stub.
+/// Used by `cargo scaffold --download` to give a freshly scaffolded day a head start.
+use std::collections::HashSet;
+
+/// Punctuation that may trail a token of a sentence pattern, e.g. the comma in `capacity -1,`.
+const TRAILING_PUNCTUATION: [char; 4] = [',', '.', ':', ';'];
+
+const KEYWORDS: [&str; 16] = [
+    "as", "break", "const", "else", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod",
+    "move", "type", "while",
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputShape {
+    /// A single line of text.
+    SingleLine,
+    /// A single line of integers, e.g. `3,4,5`.
+    IntegerLine { separator: char },
+    /// One integer per line.
+    Integers,
+    /// Lines of integers separated by the same character, e.g. `2x3x4`.
+    DelimitedIntegers { separator: char },
+    /// A rectangular grid of characters.
+    Grid {
+        width: usize,
+        height: usize,
+        digits: bool,
+    },
+    /// Blocks of lines separated by blank lines.
+    Blocks { count: usize, integers: bool },
+    /// Lines following the same sentence with varying words and numbers.
+    Pattern(Vec<Token>),
+    /// Anything else.
+    Lines,
+}
+
+/// A whitespace separated token of a sentence pattern.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    /// A word that is the same on every line.
+    Literal(String),
+    /// A word that differs between lines, followed by `suffix` punctuation.
+    Word { suffix: String },
+    /// An integer that differs between lines, followed by `suffix` punctuation.
+    Number { suffix: String },
+}
+
+/// Detects the shape of a puzzle input.
+pub fn detect(input: &str) -> InputShape {
+    let input = input.trim_end_matches(['\n', '\r']);
+    let lines: Vec<&str> = input.lines().collect();
+
+    if lines.iter().any(|line| line.trim().is_empty()) {
+        let blocks: Vec<&str> = input.split("\n\n").collect();
+        let integers = blocks
+            .iter()
+            .flat_map(|block| block.lines())
+            .all(|line| parse_integer(line.trim()).is_some());
+        return InputShape::Blocks {
+            count: blocks.len(),
+            integers,
+        };
+    }
+
+    match lines.as_slice() {
+        [] => return InputShape::Lines,
+        [line] => {
+            return match integer_separator(line) {
+                Some(separator) => InputShape::IntegerLine { separator },
+                None => InputShape::SingleLine,
+            };
+        }
+        _ => {}
+    }
+
+    let width = lines[0].chars().count();
+    let is_rectangular = width > 1
+        && lines.iter().all(|line| line.chars().count() == width)
+        && !lines.iter().any(|line| line.contains(' '));
+    let digits = lines
+        .iter()
+        .all(|line| line.chars().all(|c| c.is_ascii_digit()));
+
+    let grid = InputShape::Grid {
+        width,
+        height: lines.len(),
+        digits,
+    };
+
+    // equally long lines of digits are more likely a grid than a list of numbers.
+    if is_rectangular && digits {
+        return grid;
+    }
+
+    if lines
+        .iter()
+        .all(|line| parse_integer(line.trim()).is_some())
+    {
+        return InputShape::Integers;
+    }
+
+    let separators: HashSet<Option<char>> =
+        lines.iter().map(|line| integer_separator(line)).collect();
+    if let [Some(separator)] = separators.into_iter().collect::<Vec<_>>().as_slice() {
+        return InputShape::DelimitedIntegers {
+            separator: *separator,
+        };
+    }
+
+    if is_rectangular {
+        return grid;
+    }
+
+    detect_pattern(&lines).map_or(InputShape::Lines, InputShape::Pattern)
+}
+
+/// Parses an integer with an optional sign.
+fn parse_integer(s: &str) -> Option<i64> {
+    if s.is_empty()
+        || !s
+            .trim_start_matches(['-', '+'])
+            .starts_with(|c: char| c.is_ascii_digit())
+    {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// Returns the single separator between the integers of a line, if it only consists of those.
+fn integer_separator(line: &str) -> Option<char> {
+    let mut separators = line
+        .char_indices()
+        .filter(|(i, c)| {
+            // a `-` directly in front of a number is a sign if it does not follow a digit.
+            let is_sign = *c == '-'
+                && line[i + 1..].starts_with(|c: char| c.is_ascii_digit())
+                && !line[..*i].ends_with(|c: char| c.is_ascii_digit());
+            !c.is_ascii_digit() && !is_sign
+        })
+        .map(|(_, c)| if c.is_whitespace() { ' ' } else { c });
+
+    let separator = separators.next()?;
+    if separators.any(|c| c != separator) {
+        return None;
+    }
+
+    let numbers: Vec<&str> = line
+        .split(|c: char| {
+            if separator == ' ' {
+                c.is_whitespace()
+            } else {
+                c == separator
+            }
+        })
+        .filter(|s| !s.is_empty())
+        .collect();
+    (numbers.len() > 1 && numbers.iter().all(|n| parse_integer(n).is_some())).then_some(separator)
+}
+
+/// Splits trailing punctuation off a token, e.g. `-1,` into `-1` and `,`.
+fn split_suffix(token: &str) -> (&str, &str) {
+    let core = token.trim_end_matches(TRAILING_PUNCTUATION);
+    (core, &token[core.len()..])
+}
+
+/// Detects lines sharing the same words, with numbers or words varying in fixed positions.
+fn detect_pattern(lines: &[&str]) -> Option<Vec<Token>> {
+    let rows: Vec<Vec<&str>> = lines
+        .iter()
+        .map(|line| line.split_whitespace().collect())
+        .collect();
+    let len = rows[0].len();
+    if rows.iter().any(|row| row.len() != len) {
+        return None;
+    }
+
+    let tokens: Vec<Token> = (0..len)
+        .map(|i| {
+            let column: Vec<&str> = rows.iter().map(|row| row[i]).collect();
+            if column.iter().all(|token| *token == column[0]) {
+                return Some(Token::Literal(column[0].to_string()));
+            }
+
+            let suffix = split_suffix(column[0]).1;
+            if column.iter().any(|token| split_suffix(token).1 != suffix) {
+                return None;
+            }
+
+            let suffix = suffix.to_string();
+            if column
+                .iter()
+                .all(|token| parse_integer(split_suffix(token).0).is_some())
+            {
+                Some(Token::Number { suffix })
+            } else {
+                Some(Token::Word { suffix })
+            }
+        })
+        .collect::<Option<_>>()?;
+
+    let has = |f: fn(&Token) -> bool| tokens.iter().any(f);
+    (has(|t| matches!(t, Token::Literal(_))) && has(|t| matches!(t, Token::Number { .. })))
+        .then_some(tokens)
+}
+
+impl InputShape {
+    /// A short description of the shape, e.g. `a 10x10 grid of characters`.
+    pub fn describe(&self) -> String {
+        match self {
+            InputShape::SingleLine => "a single line of text".into(),
+            InputShape::IntegerLine { separator } => {
+                format!(
+                    "a single line of integers separated by {}",
+                    describe_separator(*separator)
+                )
+            }
+            InputShape::Integers => "one integer per line".into(),
+            InputShape::DelimitedIntegers { separator } => {
+                format!(
+                    "lines of integers separated by {}",
+                    describe_separator(*separator)
+                )
+            }
+            InputShape::Grid {
+                width,
+                height,
+                digits,
+            } => format!(
+                "a {width}x{height} grid of {}",
+                if *digits { "digits" } else { "characters" }
+            ),
+            InputShape::Blocks { count, integers } => format!(
+                "{count} blocks of {} separated by blank lines",
+                if *integers { "integers" } else { "lines" }
+            ),
+            InputShape::Pattern(_) => "lines following the same sentence pattern".into(),
+            InputShape::Lines => "lines of text".into(),
+        }
+    }
+
+    /// Generates the imports, types and `parse_input` function for this shape.
+    pub fn parser(&self) -> String {
+        let doc = format!("/// Parses the input, detected as {}.", self.describe());
+
+        let (import, return_type, body) = match self {
+            InputShape::SingleLine => (None, "&str", "input.trim()".to_string()),
+            InputShape::IntegerLine { separator } => (
+                Some("split_line_parse"),
+                "Vec<i64>",
+                format!("split_line_parse(input.trim(), {separator:?})"),
+            ),
+            InputShape::Integers => (Some("parse_lines"), "Vec<i64>", "parse_lines(input)".into()),
+            InputShape::DelimitedIntegers { separator } => (
+                Some("split_parse_lines"),
+                "Vec<Vec<i64>>",
+                format!("split_parse_lines(input, {separator:?})"),
+            ),
+            InputShape::Grid { digits: true, .. } => (
+                Some("digit_grid"),
+                "Vec<Vec<u8>>",
+                "digit_grid(input)".into(),
+            ),
+            InputShape::Grid { digits: false, .. } => (
+                Some("char_grid"),
+                "Vec<Vec<char>>",
+                "char_grid(input)".into(),
+            ),
+            InputShape::Blocks { integers: true, .. } => (
+                Some("parse_blocks"),
+                "Vec<Vec<i64>>",
+                "parse_blocks(input)".into(),
+            ),
+            InputShape::Blocks {
+                integers: false, ..
+            } => (
+                Some("blocks_of_lines"),
+                "Vec<Vec<&str>>",
+                "blocks_of_lines(input)".into(),
+            ),
+            InputShape::Pattern(tokens) => return pattern_parser(&doc, tokens),
+            InputShape::Lines => (Some("lines"), "Vec<&str>", "lines(input)".into()),
+        };
+
+        let mut code = String::new();
+        if let Some(import) = import {
+            code.push_str(&format!("use advent_of_code::utils::input::{import};\n\n"));
+        }
+        code.push_str(&format!(
+            "{doc}\nfn parse_input(input: &str) -> {return_type} {{\n    {body}\n}}\n"
+        ));
+        code
+    }
+}
+
+fn describe_separator(separator: char) -> String {
+    match separator {
+        ' ' => "whitespace".into(),
+        c => format!("`{c}`"),
+    }
+}
+
+/// Generates an `Entry` struct with a field per varying token and a parser that fills it.
+fn pattern_parser(doc: &str, tokens: &[Token]) -> String {
+    let mut fields: Vec<String> = vec![];
+    let mut values: Vec<String> = vec![];
+    let mut has_words = false;
+
+    for (i, token) in tokens.iter().enumerate() {
+        let (suffix, is_number) = match token {
+            Token::Literal(_) => continue,
+            Token::Word { suffix } => (suffix, false),
+            Token::Number { suffix } => (suffix, true),
+        };
+
+        let name = field_name(tokens, i, &values, is_number);
+        let mut chars = suffix.chars();
+        let value = match (chars.next(), chars.next()) {
+            (None, _) => format!("tokens[{i}]"),
+            (Some(c), None) => format!("tokens[{i}].trim_end_matches({c:?})"),
+            _ => format!("tokens[{i}].trim_end_matches({suffix:?})"),
+        };
+
+        if is_number {
+            fields.push(format!("    {name}: i64,"));
+            values.push(format!("{name}: {value}.parse().unwrap(),"));
+        } else {
+            has_words = true;
+            fields.push(format!("    {name}: &'a str,"));
+            values.push(format!("{name}: {value},"));
+        }
+    }
+
+    let (lifetime, elided) = if has_words {
+        ("<'a>", "<'_>")
+    } else {
+        ("", "")
+    };
+    let field_values: Vec<String> = values
+        .iter()
+        .map(|value| format!("                {value}"))
+        .collect();
+
+    format!(
+        "#[derive(Debug, Clone, PartialEq, Eq)]\n\
+        struct Entry{lifetime} {{\n{}\n}}\n\
+        \n\
+        {doc}\n\
+        fn parse_input(input: &str) -> Vec<Entry{elided}> {{\n    \
+            input\n        \
+                .lines()\n        \
+                .filter(|line| !line.is_empty())\n        \
+                .map(|line| {{\n            \
+                    let tokens: Vec<&str> = line.split_whitespace().collect();\n            \
+                    Entry {{\n{}\n            \
+                    }}\n        \
+                }})\n        \
+                .collect()\n\
+        }}\n",
+        fields.join("\n"),
+        field_values.join("\n"),
+    )
+}
+
+/// Names a field after the literal word in front of it, e.g. `capacity` for `capacity -1,`.
+/// Falls back to `name`, `word_N` or `value_N` if that word is not a usable identifier.
+/// `values` holds the `field: value` initializers generated so far.
+fn field_name(tokens: &[Token], i: usize, values: &[String], is_number: bool) -> String {
+    let is_taken = |name: &str| values.iter().any(|v| v.starts_with(&format!("{name}:")));
+
+    let literal = match i.checked_sub(1).map(|i| &tokens[i]) {
+        Some(Token::Literal(word)) => {
+            Some(word.trim_end_matches(TRAILING_PUNCTUATION).to_lowercase())
+        }
+        _ => None,
+    };
+    if let Some(name) = literal
+        && name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && !KEYWORDS.contains(&name.as_str())
+        && !is_taken(&name)
+    {
+        return name;
+    }
+
+    if !is_number && !is_taken("name") {
+        return "name".into();
+    }
+
+    let prefix = if is_number { "value" } else { "word" };
+    (1..)
+        .map(|n| format!("{prefix}_{n}"))
+        .find(|name| !is_taken(name))
+        .unwrap()
+}
+
+/// Inserts the generated parser into a module, right after the `solution!` invocation.
+/// Returns `None` if the module already has a `parse_input` function.
+pub fn insert_parser(module: &str, parser: &str) -> Option<String> {
+    if module.contains("fn parse_input") {
+        return None;
+    }
+
+    let (head, tail) = match module.find('\n') {
+        Some(pos) => module.split_at(pos + 1),
+        None => (module, ""),
+    };
+    Some(format!("{head}\n{parser}{tail}"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{InputShape, Token, detect, insert_parser};
+
+    #[test]
+    fn detects_single_lines() {
+        assert_eq!(detect("^>v<\n"), InputShape::SingleLine);
+        assert_eq!(detect("12345\n"), InputShape::SingleLine);
+        assert_eq!(
+            detect("3,4,-5\n"),
+            InputShape::IntegerLine { separator: ',' }
+        );
+        assert_eq!(
+            detect("0 2 7 0"),
+            InputShape::IntegerLine { separator: ' ' }
+        );
+    }
+
+    #[test]
+    fn detects_integers() {
+        assert_eq!(detect("1721\n979\n-366\n"), InputShape::Integers);
+        assert_eq!(
+            detect("2x3x4\n1x1x10\n"),
+            InputShape::DelimitedIntegers { separator: 'x' }
+        );
+        assert_eq!(
+            detect("1-3 5\n-2  4\n"),
+            InputShape::Lines,
+            "mixed separators are not delimited integers"
+        );
+        assert_eq!(
+            detect("5  -3\n12 4\n"),
+            InputShape::DelimitedIntegers { separator: ' ' }
+        );
+    }
+
+    #[test]
+    fn detects_grids() {
+        assert_eq!(
+            detect(".#.\n..#\n###\n"),
+            InputShape::Grid {
+                width: 3,
+                height: 3,
+                digits: false
+            }
+        );
+        assert_eq!(
+            detect("123\n456\n"),
+            InputShape::Grid {
+                width: 3,
+                height: 2,
+                digits: true
+            }
+        );
+    }
+
+    #[test]
+    fn detects_blocks() {
+        assert_eq!(
+            detect("1000\n2000\n\n3000\n"),
+            InputShape::Blocks {
+                count: 2,
+                integers: true
+            }
+        );
+        assert_eq!(
+            detect("H => HO\nO => HH\n\nHOH\n"),
+            InputShape::Blocks {
+                count: 2,
+                integers: false
+            }
+        );
+    }
+
+    #[test]
+    fn detects_sentence_patterns() {
+        let input = "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.\n\
+                     Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.\n";
+
+        let InputShape::Pattern(tokens) = detect(input) else {
+            panic!("expected a pattern");
+        };
+        assert_eq!(tokens[0], Token::Word { suffix: "".into() });
+        assert_eq!(tokens[1], Token::Literal("can".into()));
+        assert_eq!(tokens[3], Token::Number { suffix: "".into() });
+        assert_eq!(tokens.len(), 15);
+    }
+
+    #[test]
+    fn generates_pattern_parsers() {
+        let input = "Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8\n\
+                     Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3\n";
+
+        let parser = detect(input).parser();
+        assert!(parser.contains("struct Entry<'a> {\n    name: &'a str,\n    capacity: i64,"));
+        assert!(parser.contains("fn parse_input(input: &str) -> Vec<Entry<'_>> {"));
+        assert!(parser.contains("name: tokens[0].trim_end_matches(':'),"));
+        assert!(parser.contains("capacity: tokens[2].trim_end_matches(',').parse().unwrap(),"));
+        assert!(parser.contains("calories: tokens[10].parse().unwrap(),"));
+    }
+
+    #[test]
+    fn generates_helper_parsers() {
+        let parser = detect("2x3x4\n1x1x10\n").parser();
+        assert_eq!(
+            parser,
+            "use advent_of_code::utils::input::split_parse_lines;\n\n\
+            /// Parses the input, detected as lines of integers separated by `x`.\n\
+            fn parse_input(input: &str) -> Vec<Vec<i64>> {\n    \
+                split_parse_lines(input, 'x')\n\
+            }\n"
+        );
+    }
+
+    #[test]
+    fn inserts_parser_after_solution_macro() {
+        let module = "advent_of_code::solution!(1);\n\npub fn part_one() {}\n";
+        assert_eq!(
+            insert_parser(module, "fn parse_input() {}\n").unwrap(),
+            "advent_of_code::solution!(1);\n\nfn parse_input() {}\n\npub fn part_one() {}\n"
+        );
+        assert_eq!(insert_parser("fn parse_input() {}", ""), None);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
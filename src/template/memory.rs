@@ -0,0 +1,121 @@
+/// Module that measures the peak heap memory of a solution with a global allocator that `solution!` installs.
+/// Allocations are only counted while [`measure`] runs, so benchmarking pays for a single relaxed load.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
+
+static TRACKING: AtomicBool = AtomicBool::new(false);
+/// Bytes allocated since [`measure`] started, negative if it freed memory allocated before.
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+static LARGEST_PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The [`System`] allocator, counting the bytes in use while [`measure`] runs.
+pub struct PeakAlloc;
+
+impl PeakAlloc {
+    #[allow(clippy::cast_possible_wrap)]
+    fn track(grown: usize, shrunk: usize) {
+        if TRACKING.load(Ordering::Relaxed) {
+            let delta = grown as isize - shrunk as isize;
+            let current = CURRENT.fetch_add(delta, Ordering::Relaxed) + delta;
+            PEAK.fetch_max(current, Ordering::Relaxed);
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for PeakAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            Self::track(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Self::track(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        Self::track(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            Self::track(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Runs `func`, returning its result and the peak number of bytes it had allocated at once.
+/// The peak is 0 if [`PeakAlloc`] is not the global allocator.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, usize) {
+    CURRENT.store(0, Ordering::Relaxed);
+    PEAK.store(0, Ordering::Relaxed);
+    TRACKING.store(true, Ordering::Relaxed);
+    let result = func();
+    TRACKING.store(false, Ordering::Relaxed);
+
+    let peak = PEAK.load(Ordering::Relaxed).unsigned_abs();
+    LARGEST_PEAK.fetch_max(peak, Ordering::Relaxed);
+    (result, peak)
+}
+
+/// The largest peak of all [`measure`]d calls so far.
+pub fn largest_peak() -> usize {
+    LARGEST_PEAK.load(Ordering::Relaxed)
+}
+
+/// Formats a number of bytes with decimal units, e.g. `1.2 MB`.
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: usize) -> String {
+    let mut value = bytes as f64;
+    for unit in ["B", "KB", "MB"] {
+        if value < 1000.0 {
+            return if unit == "B" {
+                format!("{bytes} B")
+            } else {
+                format!("{value:.1} {unit}")
+            };
+        }
+        value /= 1000.0;
+    }
+    format!("{value:.1} GB")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PeakAlloc, format_bytes, largest_peak, measure};
+    use std::alloc::{GlobalAlloc, Layout};
+
+    #[test]
+    fn measures_peak_allocations() {
+        let layout = Layout::from_size_align(4096, 8).unwrap();
+        let ((), peak) = measure(|| unsafe {
+            let small = PeakAlloc.alloc(layout);
+            let large = PeakAlloc.realloc(small, layout, 8192);
+            PeakAlloc.dealloc(large, Layout::from_size_align(8192, 8).unwrap());
+        });
+        assert_eq!(peak, 8192);
+        assert!(largest_peak() >= 8192);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1234), "1.2 KB");
+        assert_eq!(format_bytes(1_200_000), "1.2 MB");
+        assert_eq!(format_bytes(3_000_000_000), "3.0 GB");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
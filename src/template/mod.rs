@@ -1,57 +1,136 @@
 use std::{env, fs};
 
+use input_check::InputIssue;
+
 pub mod aoc_cli;
 pub mod commands;
+pub mod memory;
 pub mod runner;
 
+pub use calendar::*;
+#[cfg(feature = "today")]
+pub use clock::*;
+pub use config::*;
 pub use day::*;
+pub use solution::*;
+pub use year::*;
 
+mod calendar;
+#[cfg(feature = "today")]
+mod clock;
+mod config;
 mod day;
+mod input_check;
+mod input_shape;
 mod readme_benchmarks;
+mod readme_chart;
+mod readme_layout;
+mod readme_progress;
 mod run_multi;
+mod solution;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Returns the name of the solution binary for a day, e.g. `2015_07`.
+#[must_use]
+pub fn get_bin_name(year: Year, day: Day) -> String {
+    format!("{year}_{day}")
+}
+
+/// Returns the path of the solution module for a day, e.g. `./src/bin/2015_07.rs`.
+/// The folder can be configured via `bin_dir` in `aoc.toml`.
+#[must_use]
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./{}/{}.rs", Config::get().bin_dir, get_bin_name(year, day))
+}
+
+/// Returns the path of a data file for a year, e.g. `data/2015/inputs/07.txt`.
+/// The root folder can be configured via `data_dir` in `aoc.toml`.
+#[must_use]
+pub fn get_data_path(folder: &str, year: Year, file_name: &str) -> String {
+    format!("{}/{year}/{folder}/{file_name}", Config::get().data_dir)
+}
+
 /// Helper function that reads a text file to a string.
+/// The contents are normalized to `\n` line endings with a trailing newline, see [`read_data_file`].
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
+    let filepath = get_data_path(folder, year, &format!("{day}.txt"));
+    read_data_file(&filepath, folder, year, day)
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
+    let filepath = get_data_path(folder, year, &format!("{day}-{part}.txt"));
+    read_data_file(&filepath, folder, year, day)
+}
+
+/// Reads and normalizes a data file, warning about anything that was fixed up.
+///
+/// # Panics
+/// Panics with a hint on how to fix it if the file is missing, or if it is an empty puzzle input.
+fn read_data_file(filepath: &str, folder: &str, year: Year, day: Day) -> String {
+    let hint = if folder == "inputs" {
+        format!(
+            "run `cargo download {} --year {year}` to fetch it.",
+            day.into_inner()
+        )
+    } else {
+        "paste the example from the puzzle description into it.".to_string()
+    };
+
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    let contents = fs::read_to_string(cwd.join(filepath))
+        .unwrap_or_else(|e| panic!("could not open input file \"{filepath}\": {e}, {hint}"));
+
+    let (contents, issues) = input_check::normalize(contents);
+    for issue in issues {
+        match issue {
+            InputIssue::Empty if folder == "inputs" => {
+                panic!("input file \"{filepath}\" {issue}, {hint}")
+            }
+            InputIssue::Empty => eprintln!("Warning: input file \"{filepath}\" {issue}, {hint}"),
+            _ => eprintln!("Warning: input file \"{filepath}\" {issue}."),
+        }
+    }
+    contents
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Instead, it can name a type implementing [`template::Solution`], e.g. `solution!(7, Day07)`.
+/// Free `part_one` and `part_two` functions are wrapped in a [`template::Solution`] that passes the input through.
+/// The year is taken from the binary name (e.g. `2015_07`), falling back to `AOC_YEAR`.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@free $day, [1, 2], part_one, part_two);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@free $day, [1], part_one, _);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@free $day, [2], _, part_two);
     };
+    ($day:expr, $solution:ty) => {
+        $crate::solution!(@consts $day);
+
+        fn main() {
+            let input = $crate::template::read_file("inputs", YEAR, DAY);
+            $crate::template::runner::run_solution::<$solution>(&input, YEAR, DAY);
+        }
+    };
+
+    (@consts $day:expr) => {
+        /// The current year.
+        const YEAR: $crate::template::Year = $crate::year!();
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
@@ -59,10 +138,48 @@ macro_rules! solution {
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
+        #[cfg(not(feature = "dhat-heap"))]
+        #[global_allocator]
+        static ALLOC: $crate::template::memory::PeakAlloc = $crate::template::memory::PeakAlloc;
+    };
+
+    (@free $day:expr, [$($part:literal),+], $one:tt, $two:tt) => {
+        $crate::solution!(@consts $day);
+
+        /// Adapts the free `part_one` and `part_two` functions to a [`Solution`](advent_of_code::template::Solution).
+        struct FreeFunctions;
+
+        impl $crate::template::Solution for FreeFunctions {
+            type Parsed<'a> = &'a str;
+            type Answer1 = Box<dyn ::std::fmt::Display>;
+            type Answer2 = Box<dyn ::std::fmt::Display>;
+
+            const PARTS: &'static [u8] = &[$($part),+];
+
+            fn parse(input: &str) -> Self::Parsed<'_> {
+                input
+            }
+
+            fn part_one(input: &Self::Parsed<'_>) -> Option<Self::Answer1> {
+                $crate::solution!(@part $one, input)
+            }
+
+            fn part_two(input: &Self::Parsed<'_>) -> Option<Self::Answer2> {
+                $crate::solution!(@part $two, input)
+            }
+        }
+
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let input = $crate::template::read_file("inputs", YEAR, DAY);
+            // Parsing only passes the input through, so there is no parse time to report.
+            $crate::template::runner::run_parts::<FreeFunctions>(&input.as_str(), YEAR, DAY);
         }
     };
+
+    (@part _, $input:ident) => {
+        None
+    };
+    (@part $func:ident, $input:ident) => {
+        $func($input).map(|answer| Box::new(answer) as Box<dyn ::std::fmt::Display>)
+    };
 }
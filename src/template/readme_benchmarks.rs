@@ -1,11 +1,19 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
 
-use crate::template::Day;
+use crate::template::readme_chart;
+use crate::template::readme_layout::TableLayout;
+use crate::template::readme_progress;
 use crate::template::timings::Timings;
+use crate::template::{Calendar, Config, Year, get_path_for_bin};
 
 static MARKER: &str = "<!--- benchmarking table --->";
+static PROGRESS_MARKER: &str = "<!--- progress table --->";
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -20,108 +28,225 @@ impl From<std::io::Error> for Error {
     }
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "{e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 pub struct TablePosition {
     pos_start: usize,
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+/// Checks that a section name can be used in a marker and a file name.
+pub fn validate_section(name: &str) -> Result<(), String> {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        Ok(())
+    } else {
+        Err(format!(
+            "invalid section name `{name}`, expected letters, digits, `-` or `_`."
+        ))
+    }
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Returns the marker of a named section, e.g. `<!--- benchmarking table:release --->`.
+fn section_marker(marker: &str, section: Option<&str>) -> String {
+    match section {
+        Some(name) => marker.replacen(" --->", &format!(":{name} --->"), 1),
+        None => marker.into(),
+    }
+}
 
-    if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+/// Returns the section that benchmarks of a year are stored in by default:
+/// the unnamed section for the default year, a section named after the year otherwise.
+pub fn default_section(year: Year) -> Option<String> {
+    (Config::get().default_year() != Some(year)).then(|| year.to_string())
+}
+
+/// Checks that the readme has the markers of a section, using the same rule as updating it, see [`locate_table`].
+/// The progress markers are optional.
+pub fn check_markers(readme: &str, section: Option<&str>) -> Result<(), String> {
+    locate_table(readme, &section_marker(MARKER, section)).map_err(|e| e.to_string())?;
+
+    let progress_marker = section_marker(PROGRESS_MARKER, section);
+    if readme.contains(&progress_marker) {
+        locate_table(readme, &progress_marker).map_err(|e| e.to_string())?;
     }
+    Ok(())
+}
 
-    let pos_start = matches
-        .first()
-        .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+/// Returns the path of the benchmark chart of a section, e.g. `.assets/benchmarks-release.svg`.
+fn get_chart_path(section: Option<&str>) -> PathBuf {
+    let chart = Path::new(&Config::get().chart);
+    match section {
+        Some(name) => {
+            let stem = chart.file_stem().unwrap_or_default().to_string_lossy();
+            let mut file_name = format!("{stem}-{name}");
+            if let Some(extension) = chart.extension() {
+                file_name = format!("{file_name}.{}", extension.to_string_lossy());
+            }
+            chart.with_file_name(file_name)
+        }
+        None => chart.into(),
+    }
+}
 
-    let pos_end = matches
-        .last()
-        .map(|m| m.0 + m.1.len())
-        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+/// Finds the table between two markers. A single marker is replaced by the table, with a marker before and after it.
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
-    Ok(TablePosition { pos_start, pos_end })
+    let (start, end) = match matches.as_slice() {
+        [] => {
+            return Err(Error::Parser(format!(
+                "could not find `{marker}`, add it where the table should go."
+            )));
+        }
+        [marker] => (marker, marker),
+        [start, end] => (start, end),
+        _ => {
+            return Err(Error::Parser(format!(
+                "expected `{marker}` at most twice, before and after the table, but found it {} times.",
+                matches.len()
+            )));
+        }
+    };
+
+    Ok(TablePosition {
+        pos_start: start.0,
+        pos_end: end.0 + end.1.len(),
+    })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(
+    year: Year,
+    section: Option<&str>,
+    layout: &TableLayout,
+    mut timings: Timings,
+    total_millis: f64,
+) -> String {
+    let marker = section_marker(MARKER, section);
+    let [header_row, alignment_row] = layout.header_rows();
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
+        marker.clone(),
+        layout.heading(),
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        header_row,
+        alignment_row,
     ];
 
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+    let calendar = Calendar::new(year);
+    let total_nanos: f64 = timings.data.iter().map(|t| t.total_nanos).sum();
+    layout.sort(&mut timings.data);
+
+    for timing in &timings.data {
+        let path = get_path_for_bin(year, timing.day);
+        let cells: Vec<String> = layout
+            .columns
+            .iter()
+            .map(|column| column.cell(timing, &calendar, &path, total_nanos))
+            .collect();
+        lines.push(format!("| {} |", cells.join(" | ")));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(String::new());
+    lines.push(format!(
+        "![Benchmarks](./{})",
+        get_chart_path(section).display()
+    ));
+    lines.push(marker);
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    year: Year,
+    section: Option<&str>,
+    layout: &TableLayout,
+    timings: Timings,
+    total_millis: f64,
+) -> Result<(), Error> {
+    // the progress table is optional and only updated if its markers are present.
+    let progress_marker = section_marker(PROGRESS_MARKER, section);
+    if s.contains(&progress_marker) {
+        let positions = locate_table(s, &progress_marker)?;
+        let mut lines = vec![progress_marker.clone()];
+        lines.extend(readme_progress::construct_progress(
+            &"#".repeat(layout.heading_level),
+            year,
+            &timings,
+            |day| get_path_for_bin(year, day),
+            readme_progress::last_modified,
+        ));
+        lines.push(progress_marker);
+        s.replace_range(positions.pos_start..positions.pos_end, &lines.join("\n"));
+    }
+
+    let positions = locate_table(s, &section_marker(MARKER, section))?;
+    let table = construct_table(year, section, layout, timings, total_millis);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
+/// Updates the README block of the given section, or the unnamed block if `section` is `None`.
+pub fn update(year: Year, timings: Timings, section: Option<&str>) -> Result<(), Error> {
+    let path = &Config::get().readme;
+    let layout = TableLayout::from_env().map_err(Error::Parser)?;
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    let svg = readme_chart::construct_svg(&timings, total_millis);
+    // the readme is updated in memory first, so nothing is written if its markers are invalid.
+    update_content(&mut readme, year, section, &layout, timings, total_millis)?;
+
+    let chart = get_chart_path(section);
+    if let Some(dir) = chart.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(chart, svg)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{MARKER, update_content};
+    use super::{MARKER, PROGRESS_MARKER, check_markers, update_content, validate_section};
+    use crate::template::Year;
+    use crate::template::readme_layout::{Column, SortOrder, TableLayout};
+
+    const YEAR: Year = Year::new(2015).unwrap();
     use crate::{day, template::timings::Timing, template::timings::Timings};
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
                     total_nanos: 9e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -131,28 +256,68 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            YEAR,
+            None,
+            &TableLayout::default(),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            YEAR,
+            None,
+            &TableLayout::default(),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            YEAR,
+            None,
+            &TableLayout::default(),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            YEAR,
+            None,
+            &TableLayout::default(),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            YEAR,
+            None,
+            &TableLayout::default(),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +325,15 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            YEAR,
+            None,
+            &TableLayout::default(),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
         let expected = [
             "foo",
             "bar",
@@ -169,15 +342,147 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2015_01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2015_02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2015_04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
+            "",
+            "![Benchmarks](./.assets/benchmarks.svg)",
             "<!--- benchmarking table --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_custom_layout() {
+        let layout = TableLayout {
+            columns: vec![Column::Day, Column::Total, Column::Percent],
+            sort: SortOrder::Time,
+            heading_level: 3,
+        };
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, YEAR, None, &layout, get_mock_timings(), 190.0).unwrap();
+        let expected = [
+            "<!--- benchmarking table --->",
+            "### Benchmarks",
+            "",
+            "| Day | Total | % of Total |",
+            "| :---: | :---: | :---:  |",
+            "| [Day 4](./src/bin/2015_04.rs) | `90.0s` | 47.4% |",
+            "| [Day 2](./src/bin/2015_02.rs) | `70.0s` | 36.8% |",
+            "| [Day 1](./src/bin/2015_01.rs) | `30.0s` | 15.8% |",
+            "",
+            "**Total: 190.00ms**",
+            "",
+            "![Benchmarks](./.assets/benchmarks.svg)",
+            "<!--- benchmarking table --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn updates_progress_table_if_present() {
+        let mut s = format!(
+            "{}{}\n{}{}",
+            PROGRESS_MARKER, PROGRESS_MARKER, MARKER, MARKER
+        );
+        update_content(
+            &mut s,
+            YEAR,
+            None,
+            &TableLayout::default(),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            YEAR,
+            None,
+            &TableLayout::default(),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
+        assert_eq!(s.matches(PROGRESS_MARKER).count(), 2);
+        assert_eq!(s.matches("## Progress").count(), 1);
+        assert_eq!(s.matches("## Benchmarks").count(), 1);
+        assert_eq!(s.contains("**Stars: 6/50**"), true);
+    }
+
+    #[test]
+    fn updates_named_sections_independently() {
+        let release = "<!--- benchmarking table:release --->";
+        let debug = "<!--- benchmarking table:debug --->";
+        let mut s = format!("{release}{release}\n{debug}{debug}");
+
+        update_content(
+            &mut s,
+            YEAR,
+            Some("release"),
+            &TableLayout::default(),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
+
+        assert_eq!(s.matches(release).count(), 2);
+        assert_eq!(s.matches(debug).count(), 2);
+        assert_eq!(s.matches("## Benchmarks").count(), 1);
+        assert!(s.contains("![Benchmarks](./.assets/benchmarks-release.svg)"));
+        assert!(s.ends_with(&format!("{debug}{debug}")));
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_named_section_not_present() {
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(
+            &mut s,
+            YEAR,
+            Some("2016"),
+            &TableLayout::default(),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn validates_section_names() {
+        assert!(validate_section("release").is_ok());
+        assert!(validate_section("2016").is_ok());
+        assert!(validate_section("").is_err());
+        assert!(validate_section("a --->").is_err());
+    }
+
+    #[test]
+    fn checks_marker_counts() {
+        let readme = format!("{MARKER}\n{MARKER}\n");
+        assert!(check_markers(&readme, None).is_ok());
+        // a single marker is where a new table is inserted.
+        assert!(check_markers(&format!("{MARKER}\n"), None).is_ok());
+        assert!(
+            check_markers(&format!("{readme}{MARKER}\n"), None)
+                .unwrap_err()
+                .contains("found it 3 times")
+        );
+        assert!(
+            check_markers(&readme, Some("2016"))
+                .unwrap_err()
+                .contains("benchmarking table:2016")
+        );
+        assert!(
+            check_markers(
+                &format!("{readme}{PROGRESS_MARKER}{PROGRESS_MARKER}{PROGRESS_MARKER}\n"),
+                None
+            )
+            .unwrap_err()
+            .contains("progress table")
+        );
+    }
 }
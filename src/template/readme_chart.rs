@@ -0,0 +1,218 @@
+/// Module that renders timing information as an SVG bar chart.
+/// The chart is plain SVG markup so it can be embedded in the readme without any dependencies.
+use std::time::Duration;
+
+use crate::template::timings::{Timings, duration_to_nanos};
+
+const WIDTH: f64 = 720.0;
+const LABEL_WIDTH: f64 = 64.0;
+const VALUE_WIDTH: f64 = 72.0;
+const HEADER_HEIGHT: f64 = 48.0;
+const AXIS_HEIGHT: f64 = 32.0;
+const BAR_HEIGHT: f64 = 8.0;
+const BAR_GAP: f64 = 2.0;
+const DAY_GAP: f64 = 8.0;
+
+const PART_COLORS: [&str; 2] = ["#4e79a7", "#f28e2b"];
+
+/// Logarithmic x-axis spanning whole decades of nanoseconds.
+struct Scale {
+    min_exp: i32,
+    max_exp: i32,
+}
+
+impl Scale {
+    fn new(values: impl Iterator<Item = f64>) -> Self {
+        let (min, max) = values
+            .filter(|v| *v > 0.0)
+            .fold((f64::INFINITY, 0_f64), |(min, max), v| {
+                (min.min(v), max.max(v))
+            });
+
+        if max == 0.0 {
+            // no timings: show a default range of 1µs - 1s.
+            return Scale {
+                min_exp: 3,
+                max_exp: 9,
+            };
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        let min_exp = min.log10().floor() as i32;
+        #[allow(clippy::cast_possible_truncation)]
+        let max_exp = (max.log10().ceil() as i32).max(min_exp + 1);
+
+        Scale { min_exp, max_exp }
+    }
+
+    fn chart_width() -> f64 {
+        WIDTH - LABEL_WIDTH - VALUE_WIDTH
+    }
+
+    /// Maps a duration in nanoseconds to a horizontal offset relative to the start of the chart area.
+    fn offset(&self, nanos: f64) -> f64 {
+        let span = f64::from(self.max_exp - self.min_exp);
+        let pos = (nanos.max(1.0).log10() - f64::from(self.min_exp)) / span;
+        pos.clamp(0.0, 1.0) * Self::chart_width()
+    }
+}
+
+fn format_decade(exp: i32) -> String {
+    let nanos = 10_u64.pow(exp.max(0).unsigned_abs());
+    format!("{:?}", Duration::from_nanos(nanos))
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+pub fn construct_svg(timings: &Timings, total_millis: f64) -> String {
+    let parts: Vec<[Option<(&str, f64)>; 2]> = timings
+        .data
+        .iter()
+        .map(|timing| {
+            [&timing.part_1, &timing.part_2].map(|part| {
+                part.as_deref()
+                    .and_then(|s| duration_to_nanos(s).map(|nanos| (s, nanos)))
+            })
+        })
+        .collect();
+
+    let scale = Scale::new(parts.iter().flatten().flatten().map(|(_, nanos)| *nanos));
+
+    let day_height = 2.0 * BAR_HEIGHT + BAR_GAP + DAY_GAP;
+    #[allow(clippy::cast_precision_loss)]
+    let rows_height = day_height * timings.data.len() as f64;
+    let height = HEADER_HEIGHT + rows_height + AXIS_HEIGHT;
+    let axis_y = HEADER_HEIGHT + rows_height;
+
+    let mut lines: Vec<String> = vec![
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{height}\" viewBox=\"0 0 {WIDTH} {height}\" font-family=\"monospace\" font-size=\"11\">"
+        ),
+        format!("<rect width=\"{WIDTH}\" height=\"{height}\" fill=\"#ffffff\"/>"),
+        format!(
+            "<text x=\"{LABEL_WIDTH}\" y=\"20\" font-size=\"14\" font-weight=\"bold\">Benchmarks (Total: {total_millis:.2}ms)</text>"
+        ),
+    ];
+
+    for (i, (color, label)) in PART_COLORS.iter().zip(["Part 1", "Part 2"]).enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let x = LABEL_WIDTH + 80.0 * i as f64;
+        lines.push(format!(
+            "<rect x=\"{x}\" y=\"30\" width=\"10\" height=\"10\" fill=\"{color}\"/>"
+        ));
+        lines.push(format!("<text x=\"{}\" y=\"39\">{label}</text>", x + 14.0));
+    }
+
+    for exp in scale.min_exp..=scale.max_exp {
+        let x = LABEL_WIDTH + scale.offset(10_f64.powi(exp));
+        lines.push(format!(
+            "<line x1=\"{x:.1}\" y1=\"{HEADER_HEIGHT}\" x2=\"{x:.1}\" y2=\"{axis_y}\" stroke=\"#e0e0e0\"/>"
+        ));
+        lines.push(format!(
+            "<text x=\"{x:.1}\" y=\"{}\" text-anchor=\"middle\" fill=\"#666666\">{}</text>",
+            axis_y + 16.0,
+            escape(&format_decade(exp))
+        ));
+    }
+
+    for (i, (timing, parts)) in timings.data.iter().zip(&parts).enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let y = HEADER_HEIGHT + day_height * i as f64 + DAY_GAP / 2.0;
+
+        lines.push(format!(
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"end\">Day {}</text>",
+            LABEL_WIDTH - 8.0,
+            y + BAR_HEIGHT + BAR_GAP / 2.0 + 4.0,
+            timing.day.into_inner()
+        ));
+
+        for (j, (part, color)) in parts.iter().zip(PART_COLORS).enumerate() {
+            #[allow(clippy::cast_precision_loss)]
+            let bar_y = y + (BAR_HEIGHT + BAR_GAP) * j as f64;
+
+            let (label, width) = match part {
+                Some((label, nanos)) => (*label, scale.offset(*nanos).max(1.0)),
+                None => ("-", 0.0),
+            };
+
+            if width > 0.0 {
+                lines.push(format!(
+                    "<rect x=\"{LABEL_WIDTH}\" y=\"{bar_y}\" width=\"{width:.1}\" height=\"{BAR_HEIGHT}\" fill=\"{color}\"/>"
+                ));
+            }
+
+            lines.push(format!(
+                "<text x=\"{:.1}\" y=\"{}\" font-size=\"9\">{}</text>",
+                LABEL_WIDTH + width + 4.0,
+                bar_y + BAR_HEIGHT - 1.0,
+                escape(label)
+            ));
+        }
+    }
+
+    lines.push(format!(
+        "<line x1=\"{LABEL_WIDTH}\" y1=\"{axis_y}\" x2=\"{}\" y2=\"{axis_y}\" stroke=\"#666666\"/>",
+        LABEL_WIDTH + Scale::chart_width()
+    ));
+    lines.push("</svg>".into());
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Scale, construct_svg};
+    use crate::{day, template::timings::Timing, template::timings::Timings};
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    part_1: Some("10.0µs".into()),
+                    part_2: Some("2.5ms".into()),
+                    total_nanos: 2_510_000_f64,
+                    ..Timing::new(day!(1))
+                },
+                Timing {
+                    part_1: Some("52.0ms".into()),
+                    part_2: None,
+                    total_nanos: 52_000_000_f64,
+                    ..Timing::new(day!(25))
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn scale_spans_whole_decades() {
+        let scale = Scale::new([10_000_f64, 2_500_000_f64, 52_000_000_f64].into_iter());
+        assert_eq!(scale.min_exp, 4);
+        assert_eq!(scale.max_exp, 8);
+        assert_eq!(scale.offset(10_000_f64), 0.0);
+        assert_eq!(scale.offset(100_000_000_f64), Scale::chart_width());
+    }
+
+    #[test]
+    fn renders_bars_per_part() {
+        let svg = construct_svg(&get_mock_timings(), 54.51);
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains("Benchmarks (Total: 54.51ms)"));
+        assert!(svg.contains(">Day 1</text>"));
+        assert!(svg.contains(">Day 25</text>"));
+        assert!(svg.contains(">2.5ms</text>"));
+        // day 25 has no second part, so only three bars are drawn.
+        assert_eq!(svg.matches("height=\"8\"").count(), 3);
+    }
+
+    #[test]
+    fn renders_empty_timings() {
+        let svg = construct_svg(&Timings::default(), 0.0);
+        assert!(svg.contains("Benchmarks (Total: 0.00ms)"));
+        assert!(!svg.contains("Day "));
+    }
+}
@@ -0,0 +1,294 @@
+/// Module that describes the layout of the benchmark table in the readme.
+/// The layout is read from the environment, see `.cargo/config.toml`:
+///  - `AOC_README_COLUMNS`: the columns to show, e.g. `{day} | {part_1} | {part_2} | {total}`.
+///  - `AOC_README_SORT`: either `day` or `time` (slowest day first).
+///  - `AOC_README_HEADING`: the markdown heading level of the table header (1 to 6).
+use std::{env, str::FromStr, time::Duration};
+
+use crate::template::Calendar;
+use crate::template::timings::Timing;
+
+/// A column of the benchmark table, referenced by its `{placeholder}` in the layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    Day,
+    Part1,
+    Part2,
+    Parse,
+    Total,
+    Percent,
+    Samples,
+    Memory,
+}
+
+const COLUMNS: [(&str, Column); 8] = [
+    ("day", Column::Day),
+    ("part_1", Column::Part1),
+    ("part_2", Column::Part2),
+    ("parse", Column::Parse),
+    ("total", Column::Total),
+    ("percent", Column::Percent),
+    ("samples", Column::Samples),
+    ("memory", Column::Memory),
+];
+
+impl Column {
+    fn title(self) -> &'static str {
+        match self {
+            Column::Day => "Day",
+            Column::Part1 => "Part 1",
+            Column::Part2 => "Part 2",
+            Column::Parse => "Parse",
+            Column::Total => "Total",
+            Column::Percent => "% of Total",
+            Column::Samples => "Samples",
+            Column::Memory => "Memory",
+        }
+    }
+
+    /// Renders the cell of this column for a single day.
+    /// `total_nanos` is the sum over all days and is used for the share of the overall time.
+    /// Parts a day does not have are rendered as `n/a`, see [`Timing::has_part`].
+    pub fn cell(
+        self,
+        timing: &Timing,
+        calendar: &Calendar,
+        path: &str,
+        total_nanos: f64,
+    ) -> String {
+        let code = |value: Option<&str>| format!("`{}`", value.unwrap_or("-"));
+        let part = |part: u8, value: Option<&str>| {
+            if value.is_none() && !timing.has_part(calendar, part) {
+                "n/a".into()
+            } else {
+                code(value)
+            }
+        };
+
+        match self {
+            Column::Day => format!("[Day {}]({})", timing.day.into_inner(), path),
+            Column::Part1 => part(1, timing.part_1.as_deref()),
+            Column::Part2 => part(2, timing.part_2.as_deref()),
+            Column::Parse => code(timing.parse.as_deref()),
+            Column::Memory => code(timing.memory.as_deref()),
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            Column::Total => format!("`{:.1?}`", Duration::from_nanos(timing.total_nanos as u64)),
+            Column::Percent => {
+                if total_nanos > 0.0 {
+                    format!("{:.1}%", timing.total_nanos / total_nanos * 100.0)
+                } else {
+                    "-".into()
+                }
+            }
+            Column::Samples => format!(
+                "{} / {}",
+                code(timing.samples_1.map(|x| x.to_string()).as_deref()),
+                code(timing.samples_2.map(|x| x.to_string()).as_deref())
+            ),
+        }
+    }
+}
+
+impl FromStr for Column {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let placeholder = s
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .ok_or_else(|| format!("expected a `{{placeholder}}` but found `{s}`."))?;
+
+        COLUMNS
+            .iter()
+            .find(|(name, _)| *name == placeholder)
+            .map(|(_, column)| *column)
+            .ok_or_else(|| {
+                let known: Vec<String> = COLUMNS.iter().map(|(n, _)| format!("{{{n}}}")).collect();
+                format!(
+                    "unknown placeholder `{s}`, expected one of: {}.",
+                    known.join(", ")
+                )
+            })
+    }
+}
+
+/// The order in which days are listed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    Day,
+    Time,
+}
+
+impl FromStr for SortOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "day" => Ok(SortOrder::Day),
+            "time" => Ok(SortOrder::Time),
+            x => Err(format!(
+                "unknown sort order `{x}`, expected `day` or `time`."
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableLayout {
+    pub columns: Vec<Column>,
+    pub sort: SortOrder,
+    pub heading_level: usize,
+}
+
+impl Default for TableLayout {
+    fn default() -> Self {
+        TableLayout {
+            columns: vec![Column::Day, Column::Part1, Column::Part2],
+            sort: SortOrder::Day,
+            heading_level: 2,
+        }
+    }
+}
+
+impl TableLayout {
+    /// Reads the layout from the environment, falling back to the default for unset values.
+    pub fn from_env() -> Result<Self, String> {
+        let mut layout = TableLayout::default();
+
+        if let Ok(columns) = env::var("AOC_README_COLUMNS") {
+            layout.columns = parse_columns(&columns)?;
+        }
+
+        if let Ok(sort) = env::var("AOC_README_SORT") {
+            layout.sort = sort.parse()?;
+        }
+
+        if let Ok(heading) = env::var("AOC_README_HEADING") {
+            layout.heading_level = parse_heading_level(&heading)?;
+        }
+
+        Ok(layout)
+    }
+
+    pub fn heading(&self) -> String {
+        format!("{} Benchmarks", "#".repeat(self.heading_level))
+    }
+
+    pub fn header_rows(&self) -> [String; 2] {
+        let titles: Vec<&str> = self.columns.iter().map(|c| c.title()).collect();
+        let alignments = vec![":---:"; self.columns.len()];
+        [
+            format!("| {} |", titles.join(" | ")),
+            format!("| {}  |", alignments.join(" | ")),
+        ]
+    }
+
+    /// Sorts timings in place according to the configured order.
+    pub fn sort(&self, data: &mut [Timing]) {
+        match self.sort {
+            SortOrder::Day => data.sort_by_key(|t| t.day),
+            SortOrder::Time => data.sort_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos)),
+        }
+    }
+}
+
+/// Parses a column layout such as `{day} | {part_1} | {part_2}`.
+pub fn parse_columns(template: &str) -> Result<Vec<Column>, String> {
+    let columns = template
+        .split('|')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(Column::from_str)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("AOC_README_COLUMNS: {e}"))?;
+
+    if columns.is_empty() {
+        return Err("AOC_README_COLUMNS: expected at least one column.".into());
+    }
+
+    Ok(columns)
+}
+
+fn parse_heading_level(s: &str) -> Result<usize, String> {
+    match s.trim().parse() {
+        Ok(level @ 1..=6) => Ok(level),
+        _ => Err(format!(
+            "AOC_README_HEADING: expected a heading level between 1 and 6 but found `{s}`."
+        )),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Column, SortOrder, TableLayout, parse_columns, parse_heading_level};
+    use crate::{
+        day,
+        template::{Calendar, Year, timings::Timing},
+    };
+
+    #[test]
+    fn parses_columns() {
+        let columns = parse_columns("{day} | {part_1} | {total} | {percent}").unwrap();
+        assert_eq!(
+            columns,
+            vec![Column::Day, Column::Part1, Column::Total, Column::Percent]
+        );
+    }
+
+    #[test]
+    fn errors_for_unknown_placeholder() {
+        let err = parse_columns("{day} | {part_3}").unwrap_err();
+        assert!(err.contains("unknown placeholder `{part_3}`"));
+        assert!(err.contains("{samples}"));
+    }
+
+    #[test]
+    fn errors_for_text_without_placeholder() {
+        let err = parse_columns("{day} | part_1").unwrap_err();
+        assert!(err.contains("expected a `{placeholder}` but found `part_1`"));
+    }
+
+    #[test]
+    fn parses_sort_order_and_heading() {
+        assert_eq!("time".parse::<SortOrder>(), Ok(SortOrder::Time));
+        assert!("fastest".parse::<SortOrder>().is_err());
+        assert_eq!(parse_heading_level("3"), Ok(3));
+        assert!(parse_heading_level("7").is_err());
+    }
+
+    #[test]
+    fn formats_default_header() {
+        let layout = TableLayout::default();
+        assert_eq!(layout.heading(), "## Benchmarks");
+        assert_eq!(
+            layout.header_rows(),
+            [
+                "| Day | Part 1 | Part 2 |".to_string(),
+                "| :---: | :---: | :---:  |".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_missing_parts() {
+        let calendar = Calendar::new(Year::new(2015).unwrap());
+        let timing = Timing {
+            part_1: Some("1ms".into()),
+            ..Timing::new(day!(25))
+        };
+        assert_eq!(Column::Part2.cell(&timing, &calendar, "", 0.0), "n/a");
+
+        let timing = Timing {
+            part_1: Some("1ms".into()),
+            ..Timing::new(day!(24))
+        };
+        assert_eq!(Column::Part2.cell(&timing, &calendar, "", 0.0), "`-`");
+
+        let timing = Timing {
+            part_2: Some("1ms".into()),
+            parts: Some(vec![2]),
+            ..Timing::new(day!(24))
+        };
+        assert_eq!(Column::Part1.cell(&timing, &calendar, "", 0.0), "n/a");
+    }
+}
@@ -0,0 +1,201 @@
+/// Module that renders a progress grid for the readme from local timing data.
+/// A part counts as solved once it has been benchmarked via `cargo time --store`.
+use std::{
+    fs,
+    path::Path,
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::timings::Timings;
+use crate::template::{Calendar, Day, Year};
+
+pub fn construct_progress(
+    heading: &str,
+    year: Year,
+    timings: &Timings,
+    path_for_bin: impl Fn(Day) -> String,
+    last_modified: impl Fn(&str) -> Option<String>,
+) -> Vec<String> {
+    let mut lines: Vec<String> = vec![
+        format!("{heading} Progress"),
+        String::new(),
+        "| Day | Part 1 | Part 2 | Last modified |".into(),
+        "| :---: | :---: | :---: | :---: |".into(),
+    ];
+
+    let calendar = Calendar::new(year);
+
+    let mut solved: Vec<(Day, [bool; 2])> = calendar
+        .days()
+        .map(|day| {
+            let timing = timings.data.iter().find(|t| t.day == day);
+            let parts = [
+                timing.is_some_and(|t| t.part_1.is_some()),
+                timing.is_some_and(|t| t.part_2.is_some()),
+            ];
+            (day, parts)
+        })
+        .collect();
+
+    // like on the website, the final day's second star is awarded once every other star is collected.
+    let all_others_solved = solved
+        .iter()
+        .filter(|(day, _)| calendar.parts(*day) > 1)
+        .all(|(_, parts)| parts[0] && parts[1]);
+
+    for (day, parts) in &mut solved {
+        if calendar.parts(*day) == 1 {
+            parts[1] = parts[0] && all_others_solved;
+        }
+    }
+
+    let stars = solved
+        .iter()
+        .flat_map(|(_, parts)| parts)
+        .filter(|x| **x)
+        .count();
+    let total = solved.len() * 2;
+
+    for (day, parts) in solved {
+        let path = path_for_bin(day);
+        let modified = last_modified(&path);
+        let label = match modified {
+            Some(_) => format!("[Day {}]({})", day.into_inner(), path),
+            None => format!("Day {}", day.into_inner()),
+        };
+        let [part_1, part_2] = parts.map(|x| if x { "⭐" } else { "" });
+
+        lines.push(format!(
+            "| {label} | {part_1} | {part_2} | {} |",
+            modified.as_deref().unwrap_or("-")
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push(format!("**Stars: {stars}/{total}**"));
+    lines
+}
+
+/// Returns the date of the last commit of a file as `YYYY-MM-DD`, or `None` if it does not exist.
+/// Falls back to the modification time if git is missing or the file is not committed yet,
+/// as checkouts reset modification times.
+pub fn last_modified(path: &str) -> Option<String> {
+    last_commit_date(path).or_else(|| {
+        let modified = fs::metadata(Path::new(path)).ok()?.modified().ok()?;
+        Some(format_date(modified))
+    })
+}
+
+fn last_commit_date(path: &str) -> Option<String> {
+    let output = Command::new("git")
+        .args(["log", "-1", "--format=%cs", "--", path])
+        .output()
+        .ok()?;
+    let date = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (output.status.success() && !date.is_empty()).then_some(date)
+}
+
+fn format_date(time: SystemTime) -> String {
+    let days = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() / 86_400);
+    let (year, month, day) = civil_from_days(days);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Converts days since the unix epoch into a (year, month, day) date.
+/// see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{civil_from_days, construct_progress, last_modified};
+    use crate::{day, template::Year, template::timings::Timing, template::timings::Timings};
+
+    #[test]
+    fn converts_days_to_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(16_770), (2015, 12, 1));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+    }
+
+    #[test]
+    fn reads_last_modified_dates() {
+        let date = last_modified("Cargo.toml").unwrap();
+        assert_eq!(date.len(), 10);
+        assert_eq!(date.matches('-').count(), 2);
+        assert_eq!(last_modified("src/bin/missing.rs"), None);
+    }
+
+    #[test]
+    fn renders_stars_per_part() {
+        let timings = Timings {
+            data: vec![
+                Timing {
+                    part_1: Some("1ms".into()),
+                    part_2: Some("2ms".into()),
+                    ..Timing::new(day!(1))
+                },
+                Timing {
+                    part_1: Some("1ms".into()),
+                    ..Timing::new(day!(2))
+                },
+            ],
+        };
+
+        let lines = construct_progress(
+            "##",
+            Year::new(2015).unwrap(),
+            &timings,
+            |day| format!("./src/bin/{day}.rs"),
+            |path| (path != "./src/bin/03.rs").then(|| "2015-12-01".to_string()),
+        );
+
+        assert_eq!(lines[0], "## Progress");
+        assert_eq!(
+            lines[4],
+            "| [Day 1](./src/bin/01.rs) | ⭐ | ⭐ | 2015-12-01 |"
+        );
+        assert_eq!(
+            lines[5],
+            "| [Day 2](./src/bin/02.rs) | ⭐ |  | 2015-12-01 |"
+        );
+        assert_eq!(lines[6], "| Day 3 |  |  | - |");
+        assert_eq!(lines.last().unwrap(), "**Stars: 3/50**");
+    }
+
+    #[test]
+    fn awards_final_star_once_all_others_are_solved() {
+        let year = Year::new(2025).unwrap();
+        let mut timings = Timings {
+            data: (1..=12)
+                .map(|day| Timing {
+                    part_1: Some("1ms".into()),
+                    part_2: (day < 12).then(|| "1ms".into()),
+                    ..Timing::new(crate::template::Day::new(day).unwrap())
+                })
+                .collect(),
+        };
+
+        let lines = construct_progress("##", year, &timings, |day| day.to_string(), |_| None);
+        assert_eq!(lines[15], "| Day 12 | ⭐ | ⭐ | - |");
+        assert_eq!(lines.last().unwrap(), "**Stars: 24/24**");
+
+        timings.data[0].part_2 = None;
+        let lines = construct_progress("##", year, &timings, |day| day.to_string(), |_| None);
+        assert_eq!(lines[15], "| Day 12 | ⭐ |  | - |");
+        assert_eq!(lines.last().unwrap(), "**Stars: 22/24**");
+    }
+}
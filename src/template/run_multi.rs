@@ -1,19 +1,24 @@
 use std::{collections::HashSet, io};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, Year};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+) -> Option<Timings> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
 
     let mut need_space = false;
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    all_days(year)
         .filter(|day| days_to_run.contains(day))
         .for_each(|day| {
             if need_space {
@@ -24,7 +29,7 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            let output = child_commands::run_solution(year, day, is_timed, is_release).unwrap();
 
             if output.is_empty() {
                 println!("Not solved.");
@@ -59,16 +64,11 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::Error;
+    use crate::template::{Day, Year, get_bin_name, get_path_for_bin, timings::duration_to_nanos};
     use std::{
         io::{BufRead, BufReader},
         path::Path,
@@ -77,14 +77,19 @@ pub mod child_commands {
     };
 
     /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    pub fn run_solution(
+        year: Year,
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+    ) -> Result<Vec<String>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !Path::new(&get_path_for_bin(year, day)).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = get_bin_name(year, day);
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
 
         if is_release {
             args.push("--release");
@@ -129,12 +134,19 @@ pub mod child_commands {
     }
 
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+        let mut timings = super::Timing::new(day);
+
+        timings.memory = output
+            .iter()
+            .find_map(|l| l.strip_prefix("Memory:"))
+            .map(|memory| memory.trim().into());
+
+        // solutions only print the parts they declare via `solution!`.
+        let parts: Vec<u8> = [1, 2]
+            .into_iter()
+            .filter(|part| output.iter().any(|l| l.contains(&format!("Part {part}:"))))
+            .collect();
+        timings.parts = (!parts.is_empty()).then_some(parts);
 
         output
             .iter()
@@ -149,27 +161,41 @@ pub mod child_commands {
                 };
 
                 let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
+                let shared = l.contains("(with part 1:");
+                Some((part, timing_str, nanos, parse_samples(l), shared))
             })
-            .for_each(|(part, timing_str, nanos)| {
+            .for_each(|(part, timing_str, nanos, samples, shared)| {
                 if part.contains("Part 1") {
                     timings.part_1 = Some(timing_str.into());
+                    timings.samples_1 = samples;
                 } else if part.contains("Part 2") {
                     timings.part_2 = Some(timing_str.into());
+                    timings.samples_2 = samples;
+                } else if part.contains("Parse") {
+                    // parts are timed on the parsed input, so parsing adds to the total.
+                    timings.parse = Some(timing_str.into());
                 }
 
-                timings.total_nanos += nanos;
+                // a part solved together with part 1 repeats its time.
+                if !shared {
+                    timings.total_nanos += nanos;
+                }
             });
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    fn parse_samples(line: &str) -> Option<u64> {
+        line.split(" samples)")
+            .next()?
+            .rsplit('@')
+            .next()?
+            .trim()
+            .parse()
+            .ok()
     }
 
     fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
         let str_timing = line
             .split(" samples)")
             .next()?
@@ -177,16 +203,10 @@ pub mod child_commands {
             .next_back()?
             .split('@')
             .next()?
+            .trim_start_matches("with part 1:")
             .trim();
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+        Some((str_timing, duration_to_nanos(str_timing)?))
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -208,6 +228,7 @@ pub mod child_commands {
         use super::parse_exec_time;
 
         use crate::day;
+        use crate::template::{Calendar, Year};
 
         #[test]
         fn parses_execution_times() {
@@ -222,6 +243,43 @@ pub mod child_commands {
             assert_approx_eq!(res.total_nanos, 74130074.13_f64);
             assert_eq!(res.part_1.unwrap(), "74.13ns");
             assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_eq!(res.samples_1, Some(100000));
+            assert_eq!(res.samples_2, Some(99999));
+        }
+
+        #[test]
+        fn parses_shared_parts() {
+            let res = parse_exec_time(
+                &[
+                    "Parse: (1.5µs @ 1000 samples)".into(),
+                    "Part 1: 3 (2.5µs @ 1000 samples)".into(),
+                    "Part 2: 4 (with part 1: 2.5µs @ 1000 samples)".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 4000_f64);
+            assert_eq!(res.part_1.as_deref(), Some("2.5µs"));
+            assert_eq!(res.part_2.as_deref(), Some("2.5µs"));
+            assert_eq!(res.samples_2, Some(1000));
+            assert_eq!(res.parts, Some(vec![1, 2]));
+            assert!(res.is_complete(&Calendar::new(Year::new(2015).unwrap())));
+        }
+
+        #[test]
+        fn parses_reported_details() {
+            let res = parse_exec_time(
+                &[
+                    "Parse: (1.5µs @ 1000 samples)".into(),
+                    "Part 1: 0 (2.5µs @ 1000 samples)".into(),
+                    "Memory: 1.2 MB".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 4000_f64);
+            assert_eq!(res.parse.unwrap(), "1.5µs");
+            assert_eq!(res.memory.unwrap(), "1.2 MB");
         }
 
         #[test]
@@ -252,6 +310,17 @@ pub mod child_commands {
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(res.parts, Some(vec![1, 2]));
+        }
+
+        #[test]
+        fn parses_declared_parts() {
+            let res = parse_exec_time(
+                &["Part 1: 10 (1.5ms @ 100 samples)".into(), "".into()],
+                day!(25),
+            );
+            assert_eq!(res.part_1.unwrap(), "1.5ms");
+            assert_eq!(res.parts, Some(vec![1]));
         }
     }
 }
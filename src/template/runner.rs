@@ -3,13 +3,21 @@ use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{Write, stdout};
 use std::process::Output;
+use std::sync::Once;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, panic, process};
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Config, Day, Solution, Year, aoc_cli, memory};
+use crate::utils::ParseError;
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+fn run_part<I: Copy, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+) {
     let part_str = format!("Part {part}");
 
     let (result, duration, samples) =
@@ -18,13 +26,90 @@ pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, da
     print_result(&result, &part_str, &format_duration(&duration, samples));
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        submit_result(result, year, day, part);
+    }
+}
+
+/// Parses the input of a [`Solution`] once, reporting the time spent parsing on its own line, and runs its parts.
+pub fn run_solution<S: Solution>(input: &str, year: Year, day: Day) {
+    report_parse_errors();
+    let (parsed, duration, samples) = run_timed(S::parse, input, |_| print!("Parse:"));
+    print!("\r");
+    println!("Parse:{}", format_duration(&duration, samples));
+
+    run_parts::<S>(&parsed, year, day);
+}
+
+/// Runs the parts of a [`Solution`] on its parsed input, so the time of each part excludes parsing.
+/// If [`Solution::SHARED`] is set, both parts are solved and timed together with [`Solution::both`].
+pub fn run_parts<S: Solution>(parsed: &S::Parsed<'_>, year: Year, day: Day) {
+    report_parse_errors();
+    if !S::SHARED {
+        for part in S::PARTS {
+            match part {
+                1 => run_part(S::part_one, parsed, year, day, 1),
+                2 => run_part(S::part_two, parsed, year, day, 2),
+                _ => {}
+            }
+        }
+        print_memory();
+        return;
+    }
+
+    let ((one, two), duration, samples) =
+        run_timed(S::both, parsed, |(one, _)| print_result(one, "Part 1", ""));
+    print_result(&one, "Part 1", &format_duration(&duration, samples));
+    if S::PARTS.contains(&2) {
+        // the time is repeated so part 2 is benchmarked as well, `cargo time` counts it once.
+        let timing = format!(" (with part 1: {})", format_timing(&duration, samples));
+        print_result(&two, "Part 2", &timing);
     }
+
+    if let Some(one) = one {
+        submit_result(one, year, day, 1);
+    }
+    if let Some(two) = two {
+        submit_result(two, year, day, 2);
+    }
+    print_memory();
+}
+
+/// Prints the peak heap memory of parsing and each part, as measured by the allocator `solution!` installs.
+fn print_memory() {
+    // dhat replaces the allocator that measures memory.
+    if cfg!(not(feature = "dhat-heap")) {
+        println!("Memory: {}", memory::format_bytes(memory::largest_peak()));
+    }
+}
+
+/// Installs a panic hook that renders a [`ParseError`] raised by the input helpers as a snippet of the input.
+/// Other panics are reported by the previous hook.
+pub fn report_parse_errors() {
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            match info.payload().downcast_ref::<ParseError>() {
+                Some(error) => {
+                    let location = info
+                        .location()
+                        .map(|l| format!(" at {}:{}", l.file(), l.line()))
+                        .unwrap_or_default();
+                    eprintln!(
+                        "\nfailed to parse the input{location}:\n{}",
+                        error.snippet()
+                    );
+                }
+                None => previous(info),
+            }
+        }));
+    });
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///  2. in release, the function is benched (approx. `bench_budget_ms` of execution time or 10 samples, whatever take longer.)
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
@@ -35,7 +120,8 @@ fn run_timed<I: Copy, T>(
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        // only the first run is measured, benchmarking would repeat the same allocations.
+        memory::measure(|| func(input)).0
     };
     let base_time = timer.elapsed();
 
@@ -56,8 +142,9 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let bench_iterations = (Config::get().bench_budget.as_nanos()
+        / cmp::max(base_time.as_nanos(), 10))
+    .clamp(10, 10000);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -83,10 +170,14 @@ fn average_duration(numbers: &[Duration]) -> u128 {
 }
 
 fn format_duration(duration: &Duration, samples: u128) -> String {
+    format!(" ({})", format_timing(duration, samples))
+}
+
+fn format_timing(duration: &Duration, samples: u128) -> String {
     if samples == 1 {
-        format!(" ({duration:.1?})")
+        format!("{duration:.1?}")
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!("{duration:.1?} @ {samples} samples")
     }
 }
 
@@ -130,6 +221,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 ///  2. aoc-cli is installed.
 fn submit_result<T: Display>(
     result: T,
+    year: Year,
     day: Day,
     part: u8,
 ) -> Option<Result<Output, aoc_cli::AocCommandError>> {
@@ -163,5 +255,5 @@ fn submit_result<T: Display>(
     }
 
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    Some(aoc_cli::submit(year, day, part, &result.to_string()))
 }
@@ -0,0 +1,129 @@
+use std::fmt::Display;
+
+/// A puzzle solution as a type, an alternative to the free `part_one` and `part_two` functions.
+/// The input is parsed once and shared by both parts, answers can be anything that displays.
+///
+/// Wire it up with `solution!(7, Day07)`, which parses the input once, reports the time spent parsing
+/// separately and runs the parts listed in [`Solution::PARTS`] on the parsed input.
+///
+/// ```
+/// use advent_of_code::template::Solution;
+///
+/// struct Day01;
+///
+/// impl Solution for Day01 {
+///     type Parsed<'a> = Vec<i64>;
+///     type Answer1 = i64;
+///     type Answer2 = i64;
+///
+///     fn parse(input: &str) -> Self::Parsed<'_> {
+///         input.lines().map(|l| l.parse().unwrap()).collect()
+///     }
+///
+///     fn part_one(numbers: &Self::Parsed<'_>) -> Option<Self::Answer1> {
+///         Some(numbers.iter().sum())
+///     }
+///
+///     fn part_two(numbers: &Self::Parsed<'_>) -> Option<Self::Answer2> {
+///         numbers.iter().max().copied()
+///     }
+/// }
+///
+/// assert_eq!(Day01::solve("1\n2\n"), (Some(3), Some(2)));
+/// ```
+pub trait Solution {
+    /// The parsed puzzle input, which may borrow from the input.
+    type Parsed<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// The parts of the puzzle, the final day of an event only has one.
+    const PARTS: &'static [u8] = &[1, 2];
+
+    /// Set this when overriding [`Solution::both`], so the runner solves and times both parts together.
+    const SHARED: bool = false;
+
+    fn parse(input: &str) -> Self::Parsed<'_>;
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Option<Self::Answer1>;
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Option<Self::Answer2>;
+
+    /// Solves both parts, override this if they share work.
+    fn both(parsed: &Self::Parsed<'_>) -> (Option<Self::Answer1>, Option<Self::Answer2>) {
+        (Self::part_one(parsed), Self::part_two(parsed))
+    }
+
+    /// Parses an input and solves both parts, e.g. to check an example in tests.
+    fn solve(input: &str) -> (Option<Self::Answer1>, Option<Self::Answer2>) {
+        Self::both(&Self::parse(input))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Solution;
+
+    struct Words;
+
+    impl Solution for Words {
+        type Parsed<'a> = Vec<&'a str>;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        const PARTS: &'static [u8] = &[1, 2];
+
+        fn parse(input: &str) -> Self::Parsed<'_> {
+            input.split_whitespace().collect()
+        }
+
+        fn part_one(words: &Self::Parsed<'_>) -> Option<Self::Answer1> {
+            Some(words.len())
+        }
+
+        fn part_two(words: &Self::Parsed<'_>) -> Option<Self::Answer2> {
+            words
+                .iter()
+                .max_by_key(|w| w.len())
+                .map(|w| w.to_uppercase())
+        }
+    }
+
+    struct FinalDay;
+
+    impl Solution for FinalDay {
+        type Parsed<'a> = &'a str;
+        type Answer1 = u8;
+        type Answer2 = u8;
+
+        const PARTS: &'static [u8] = &[1];
+
+        fn parse(input: &str) -> Self::Parsed<'_> {
+            input
+        }
+
+        fn part_one(_: &Self::Parsed<'_>) -> Option<Self::Answer1> {
+            Some(25)
+        }
+
+        fn part_two(_: &Self::Parsed<'_>) -> Option<Self::Answer2> {
+            None
+        }
+    }
+
+    #[test]
+    fn solves_both_parts() {
+        assert_eq!(Words::solve("a bb ccc"), (Some(3), Some("CCC".into())));
+        assert_eq!(Words::solve(""), (Some(0), None));
+    }
+
+    #[test]
+    fn supports_single_part_days() {
+        assert_eq!(FinalDay::PARTS, &[1]);
+        assert_eq!(FinalDay::solve(""), (Some(25), None));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
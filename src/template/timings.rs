@@ -1,9 +1,9 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{Calendar, Config, Day, Year};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+static TIMINGS_FILE_NAME: &str = "timings";
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
@@ -12,6 +12,48 @@ pub struct Timing {
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    /// Number of benchmark samples per part.
+    pub samples_1: Option<u64>,
+    pub samples_2: Option<u64>,
+    /// Input parsing time, if reported by the solution.
+    pub parse: Option<String>,
+    /// Peak memory usage, if reported by the solution.
+    pub memory: Option<String>,
+    /// The parts a solution declares via `solution!`, if reported.
+    pub parts: Option<Vec<u8>>,
+}
+
+impl Timing {
+    /// Creates an empty timing for a day that has not been benchmarked.
+    pub fn new(day: Day) -> Self {
+        Timing {
+            day,
+            part_1: None,
+            part_2: None,
+            total_nanos: 0_f64,
+            samples_1: None,
+            samples_2: None,
+            parse: None,
+            memory: None,
+            parts: None,
+        }
+    }
+
+    /// Returns `true` if the day has the given part.
+    /// Uses the parts reported by the solution, falling back to the event calendar.
+    pub fn has_part(&self, calendar: &Calendar, part: u8) -> bool {
+        match &self.parts {
+            Some(parts) => parts.contains(&part),
+            None => part <= calendar.parts(self.day),
+        }
+    }
+
+    /// Returns `true` if every part of the day has been benchmarked.
+    pub fn is_complete(&self, calendar: &Calendar) -> bool {
+        [(1, &self.part_1), (2, &self.part_2)]
+            .iter()
+            .all(|(part, timing)| timing.is_some() || !self.has_part(calendar, *part))
+    }
 }
 
 /// Represents benchmark times for a set of days.
@@ -23,15 +65,15 @@ pub struct Timings {
 
 impl Timings {
     /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    pub fn store_file(&self, year: Year, section: Option<&str>) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(get_file_path(year, section))?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    pub fn read_from_file(year: Year, section: Option<&str>) -> Self {
+        fs::read_to_string(get_file_path(year, section))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,7 +93,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -60,13 +102,41 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
+    /// Returns `true` if every part of the day has been benchmarked.
+    /// Days with a single part are complete once that part is, see [`Timing::has_part`].
+    pub fn is_day_complete(&self, year: Year, day: Day) -> bool {
+        let calendar = Calendar::new(year);
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.day == day && t.is_complete(&calendar))
+    }
+}
+
+/// Timings are stored per year next to its data, e.g. `data/2015/timings.json`.
+/// Named README sections keep their timings in separate files, e.g. `timings-release.json`.
+pub fn get_file_path(year: Year, section: Option<&str>) -> String {
+    let file_name = match section {
+        Some(name) => format!("{TIMINGS_FILE_NAME}-{name}.json"),
+        None => format!("{TIMINGS_FILE_NAME}.json"),
+    };
+    format!("{}/{year}/{file_name}", Config::get().data_dir)
+}
+
+/// Converts a formatted duration such as `12.7ms` back into nanoseconds.
+pub fn duration_to_nanos(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    match s {
+        s if s.contains("ns") => parse_to_float(s, "ns"),
+        s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
+        s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
     }
 }
 
+fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
+    s.split(postfix).next()?.parse().ok()
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl From<Timings> for JsonValue {
@@ -133,6 +203,35 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        // optional details are only written when present to keep the file compact.
+        for (key, samples) in [
+            ("samples_1", value.samples_1),
+            ("samples_2", value.samples_2),
+        ] {
+            if let Some(samples) = samples {
+                #[allow(clippy::cast_precision_loss)]
+                map.insert(key.into(), JsonValue::Number(samples as f64));
+            }
+        }
+
+        for (key, detail) in [("parse", &value.parse), ("memory", &value.memory)] {
+            if let Some(detail) = detail {
+                map.insert(key.into(), JsonValue::String(detail.clone()));
+            }
+        }
+
+        if let Some(parts) = &value.parts {
+            map.insert(
+                "parts".into(),
+                JsonValue::Array(
+                    parts
+                        .iter()
+                        .map(|part| JsonValue::Number(f64::from(*part)))
+                        .collect(),
+                ),
+            );
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -166,11 +265,35 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let get_samples = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|v| *v as u64)
+        };
+        let get_detail = |key: &str| json.get(key).and_then(|v| v.get::<String>()).cloned();
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let parts = json
+            .get("parts")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .map(|parts| {
+                parts
+                    .iter()
+                    .filter_map(|part| part.get::<f64>().map(|part| *part as u8))
+                    .collect()
+            });
+
         Ok(Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
             total_nanos,
+            samples_1: get_samples("samples_1"),
+            samples_2: get_samples("samples_2"),
+            parse: get_detail("parse"),
+            memory: get_detail("memory"),
+            parts,
         })
     }
 }
@@ -187,22 +310,22 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
                     total_nanos: 4e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -223,6 +346,13 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_reported_parts() {
+            let json = r#"{ "data": [{ "day": "25", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "parts": [1] }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].parts, Some(vec![1]));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -271,49 +401,85 @@ mod tests {
     mod is_day_complete {
         use crate::{
             day,
+            template::Year,
             template::timings::{Timing, Timings},
         };
 
+        const YEAR: Year = Year::new(2015).unwrap();
+
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(YEAR, day!(1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(YEAR, day!(1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
+                    ..Timing::new(day!(1))
+                }],
+            };
+
+            assert_eq!(timings.is_day_complete(YEAR, day!(1)), false);
+        }
+
+        #[test]
+        fn handles_single_part_days() {
+            let timings = Timings {
+                data: vec![Timing {
+                    part_1: Some("1ms".into()),
+                    part_2: None,
+                    total_nanos: 1_000_000_f64,
+                    ..Timing::new(day!(25))
+                }],
+            };
+
+            assert_eq!(timings.is_day_complete(YEAR, day!(25)), true);
+            assert_eq!(
+                timings.is_day_complete(Year::new(2025).unwrap(), day!(25)),
+                false
+            );
+        }
+
+        #[test]
+        fn handles_declared_parts() {
+            let timings = Timings {
+                data: vec![Timing {
+                    part_1: None,
+                    part_2: Some("2ms".into()),
+                    total_nanos: 2_000_000_f64,
+                    parts: Some(vec![2]),
+                    ..Timing::new(day!(3))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(YEAR, day!(3)), true);
         }
     }
 
@@ -330,10 +496,10 @@ mod tests {
             let timings = get_mock_timings();
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(3),
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    ..Timing::new(day!(3))
                 }],
             };
             let merged = timings.merge(&other);
@@ -350,10 +516,10 @@ mod tests {
 
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(2),
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    ..Timing::new(day!(2))
                 }],
             };
             let merged = timings.merge(&other);
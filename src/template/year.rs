@@ -0,0 +1,164 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "today")]
+use chrono::{Datelike, FixedOffset, Utc};
+
+#[cfg(feature = "today")]
+use crate::template::SERVER_UTC_OFFSET;
+
+/// The first year Advent of Code took place.
+const FIRST_YEAR: u16 = 2015;
+
+/// A valid Advent of Code event year (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2015).unwrap();
+/// assert_eq!(year.to_string(), "2015")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR || year > 9999 {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the default year configured via the `AOC_YEAR` environment variable.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Extracts the year from a year-qualified binary name such as `2015_07`.
+    pub const fn from_bin_name(name: &str) -> Option<Self> {
+        let bytes = name.as_bytes();
+        if bytes.len() < 5 || bytes[4] != b'_' {
+            return None;
+        }
+        match parse_year(bytes) {
+            Some(year) => Self::new(year),
+            None => None,
+        }
+    }
+
+    /// Returns the year compiled into a solution binary: the year prefix of the binary name,
+    /// falling back to `AOC_YEAR` at compile time.
+    pub const fn for_bin(name: &str, default: Option<&str>) -> Option<Self> {
+        if let Some(year) = Self::from_bin_name(name) {
+            return Some(year);
+        }
+        match default {
+            Some(year) if year.len() == 4 => match parse_year(year.as_bytes()) {
+                Some(year) => Self::new(year),
+                None => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Parses the first four bytes as a decimal year.
+const fn parse_year(bytes: &[u8]) -> Option<u16> {
+    let mut value = 0;
+    let mut i = 0;
+    while i < 4 {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+        value = value * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+    Some(value)
+}
+
+#[cfg(feature = "today")]
+impl Year {
+    /// Returns the current year in server time.
+    pub fn today() -> Option<Self> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        Self::new(u16::try_from(Utc::now().with_timezone(&offset).year()).ok()?)
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates the [`Year`] of the solution binary being compiled in a const context.
+#[macro_export]
+macro_rules! year {
+    () => {
+        const {
+            $crate::template::Year::for_bin(env!("CARGO_BIN_NAME"), option_env!("AOC_YEAR"))
+                .expect("could not determine year, name the binary `YYYY_DD` or set `AOC_YEAR`")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2015".parse::<Year>().unwrap(), Year(2015));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("twenty".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn reads_year_from_bin_name() {
+        assert_eq!(Year::from_bin_name("2016_07"), Some(Year(2016)));
+        assert_eq!(Year::from_bin_name("07"), None);
+        assert_eq!(Year::from_bin_name("20x6_07"), None);
+        assert_eq!(Year::for_bin("07", Some("2015")), Some(Year(2015)));
+        assert_eq!(Year::for_bin("2017_01", Some("2015")), Some(Year(2017)));
+        assert_eq!(Year::for_bin("07", None), None);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
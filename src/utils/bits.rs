@@ -0,0 +1,481 @@
+/// Module with bit sets and bit tricks for enumerating subsets, e.g. of containers or gifts, without allocating.
+use std::fmt::Debug;
+use std::ops::Range;
+
+/// The words a [`BitSet`] stores its bits in, growable for a `Vec` and fixed for an array.
+pub trait Words: AsRef<[u64]> + AsMut<[u64]> {
+    /// No words for a `Vec`, all words zero for an array.
+    fn zeroed() -> Self;
+
+    /// Makes room for at least `len` words, panics if the words are fixed and fewer.
+    fn grow(&mut self, len: usize);
+}
+
+impl Words for Vec<u64> {
+    fn zeroed() -> Self {
+        vec![]
+    }
+
+    fn grow(&mut self, len: usize) {
+        if self.len() < len {
+            self.resize(len, 0);
+        }
+    }
+}
+
+impl<const N: usize> Words for [u64; N] {
+    fn zeroed() -> Self {
+        [0; N]
+    }
+
+    fn grow(&mut self, len: usize) {
+        assert!(
+            len <= N,
+            "a FixedBitSet<{N}> only holds bits below {}",
+            N * 64
+        );
+    }
+}
+
+/// A set of small integers stored as one bit each. Growable by default, see [`FixedBitSet`] for a fixed size.
+/// Like [`Interner`](crate::utils::Interner), `BitSet::new()` needs a type annotation to use the default.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BitSet<W = Vec<u64>> {
+    words: W,
+}
+
+/// A bit set of `WORDS * 64` bits that lives on the stack.
+pub type FixedBitSet<const WORDS: usize> = BitSet<[u64; WORDS]>;
+
+impl<W: Words> BitSet<W> {
+    pub fn new() -> Self {
+        Self { words: W::zeroed() }
+    }
+
+    /// Creates a set that can hold `0..bits` without growing.
+    pub fn with_capacity(bits: usize) -> Self {
+        let mut set = Self::new();
+        set.words.grow(bits.div_ceil(64));
+        set
+    }
+
+    /// The number of bits the set can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.words.as_ref().len() * 64
+    }
+
+    pub fn contains(&self, i: usize) -> bool {
+        self.words
+            .as_ref()
+            .get(i / 64)
+            .is_some_and(|word| word >> (i % 64) & 1 == 1)
+    }
+
+    /// Adds `i`, returning whether it was not in the set yet.
+    pub fn insert(&mut self, i: usize) -> bool {
+        let word = self.word_mut(i);
+        let absent = *word >> (i % 64) & 1 == 0;
+        *word |= 1 << (i % 64);
+        absent
+    }
+
+    /// Removes `i`, returning whether it was in the set.
+    pub fn remove(&mut self, i: usize) -> bool {
+        let present = self.contains(i);
+        if present {
+            *self.word_mut(i) &= !(1 << (i % 64));
+        }
+        present
+    }
+
+    pub fn toggle(&mut self, i: usize) {
+        *self.word_mut(i) ^= 1 << (i % 64);
+    }
+
+    /// Adds every bit of `range`, a word at a time.
+    pub fn insert_range(&mut self, range: Range<usize>) {
+        self.update_range(range, |word, mask| *word |= mask);
+    }
+
+    pub fn remove_range(&mut self, range: Range<usize>) {
+        self.update_range(range, |word, mask| *word &= !mask);
+    }
+
+    pub fn toggle_range(&mut self, range: Range<usize>) {
+        self.update_range(range, |word, mask| *word ^= mask);
+    }
+
+    pub fn clear(&mut self) {
+        self.words.as_mut().fill(0);
+    }
+
+    /// The number of bits in the set.
+    pub fn len(&self) -> usize {
+        self.words
+            .as_ref()
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.as_ref().iter().all(|&word| word == 0)
+    }
+
+    /// The number of bits in the set that are smaller than `i`.
+    pub fn rank(&self, i: usize) -> usize {
+        let words = self.words.as_ref();
+        let whole = (i / 64).min(words.len());
+        let partial = match words.get(i / 64) {
+            Some(word) if !i.is_multiple_of(64) => (word << (64 - i % 64)).count_ones() as usize,
+            _ => 0,
+        };
+        words[..whole]
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum::<usize>()
+            + partial
+    }
+
+    /// The smallest bit in the set.
+    pub fn first(&self) -> Option<usize> {
+        self.next_set(0)
+    }
+
+    /// Iterates over the bits in the set in increasing order.
+    pub fn iter(&self) -> Iter<'_> {
+        let words = self.words.as_ref();
+        Iter {
+            words,
+            index: 0,
+            word: words.first().copied().unwrap_or(0),
+        }
+    }
+
+    pub fn union_with<V: Words>(&mut self, other: &BitSet<V>) {
+        let other = other.words.as_ref();
+        self.words.grow(other.len());
+        for (word, other) in self.words.as_mut().iter_mut().zip(other) {
+            *word |= other;
+        }
+    }
+
+    pub fn intersect_with<V: Words>(&mut self, other: &BitSet<V>) {
+        let other = other.words.as_ref();
+        for (i, word) in self.words.as_mut().iter_mut().enumerate() {
+            *word &= other.get(i).copied().unwrap_or(0);
+        }
+    }
+
+    pub fn difference_with<V: Words>(&mut self, other: &BitSet<V>) {
+        for (word, other) in self.words.as_mut().iter_mut().zip(other.words.as_ref()) {
+            *word &= !other;
+        }
+    }
+
+    pub fn is_subset<V: Words>(&self, other: &BitSet<V>) -> bool {
+        let other = other.words.as_ref();
+        self.words
+            .as_ref()
+            .iter()
+            .enumerate()
+            .all(|(i, word)| word & !other.get(i).copied().unwrap_or(0) == 0)
+    }
+
+    pub fn is_disjoint<V: Words>(&self, other: &BitSet<V>) -> bool {
+        self.words
+            .as_ref()
+            .iter()
+            .zip(other.words.as_ref())
+            .all(|(word, other)| word & other == 0)
+    }
+
+    /// Advances a set of `k` bits below `n` to the next one in the order of [`k_subsets`], in place.
+    /// Returns `false`, leaving the set as is, when it was the last one. Start from `insert_range(0..k)`.
+    pub fn next_k_subset(&mut self, n: usize) -> bool {
+        // Gosper's hack for any number of words: the lowest run of ones moves its top bit one up
+        // and the rest of the run to the bottom.
+        let Some(low) = self.first() else {
+            return false;
+        };
+        let high = self.next_unset(low);
+        if high >= n {
+            return false;
+        }
+        if high < 64 {
+            let word = &mut self.words.as_mut()[0];
+            *word = (*word & (u64::MAX << high)) | (1 << high) | ((1 << (high - low - 1)) - 1);
+            return true;
+        }
+        self.insert(high);
+        self.remove_range(0..high);
+        self.insert_range(0..high - low - 1);
+        true
+    }
+
+    /// The smallest bit in the set that is at least `from`.
+    fn next_set(&self, from: usize) -> Option<usize> {
+        let words = self.words.as_ref();
+        let mut i = from / 64;
+        let mut word = words.get(i)? & (u64::MAX << (from % 64));
+        loop {
+            if word != 0 {
+                return Some(i * 64 + word.trailing_zeros() as usize);
+            }
+            i += 1;
+            word = *words.get(i)?;
+        }
+    }
+
+    /// The smallest bit not in the set that is at least `from`.
+    fn next_unset(&self, from: usize) -> usize {
+        let words = self.words.as_ref();
+        let mut i = from / 64;
+        let mut word = match words.get(i) {
+            Some(word) => !word & (u64::MAX << (from % 64)),
+            None => return from,
+        };
+        loop {
+            if word != 0 {
+                return i * 64 + word.trailing_zeros() as usize;
+            }
+            i += 1;
+            word = match words.get(i) {
+                Some(word) => !word,
+                None => return i * 64,
+            };
+        }
+    }
+
+    fn word_mut(&mut self, i: usize) -> &mut u64 {
+        self.words.grow(i / 64 + 1);
+        &mut self.words.as_mut()[i / 64]
+    }
+
+    /// Applies `f` to each word overlapping `range` with a mask of the bits of the range in that word.
+    fn update_range(&mut self, range: Range<usize>, f: impl Fn(&mut u64, u64)) {
+        if range.is_empty() {
+            return;
+        }
+        self.words.grow(range.end.div_ceil(64));
+        let words = self.words.as_mut();
+        let (first, last) = (range.start / 64, (range.end - 1) / 64);
+
+        for (i, word) in words.iter_mut().enumerate().take(last + 1).skip(first) {
+            let low = if i == first { range.start % 64 } else { 0 };
+            let high = if i == last { (range.end - 1) % 64 } else { 63 };
+            f(word, (u64::MAX >> (63 - high)) & (u64::MAX << low));
+        }
+    }
+}
+
+impl<W: Words> Default for BitSet<W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W: Words> Debug for BitSet<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<W: Words> Extend<usize> for BitSet<W> {
+    fn extend<T: IntoIterator<Item = usize>>(&mut self, bits: T) {
+        for bit in bits {
+            self.insert(bit);
+        }
+    }
+}
+
+impl<W: Words> FromIterator<usize> for BitSet<W> {
+    fn from_iter<T: IntoIterator<Item = usize>>(bits: T) -> Self {
+        let mut set = Self::new();
+        set.extend(bits);
+        set
+    }
+}
+
+/// The iterator of [`BitSet::iter`].
+pub struct Iter<'a> {
+    words: &'a [u64],
+    index: usize,
+    word: u64,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.word == 0 {
+            self.index += 1;
+            self.word = *self.words.get(self.index)?;
+        }
+        let bit = self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1;
+        Some(self.index * 64 + bit)
+    }
+}
+
+/// Iterates over the set bits of `mask` in increasing order, clearing the lowest set bit each step.
+pub fn ones(mut mask: u64) -> impl Iterator<Item = usize> {
+    std::iter::from_fn(move || {
+        if mask == 0 {
+            return None;
+        }
+        let bit = mask.trailing_zeros() as usize;
+        mask &= mask - 1;
+        Some(bit)
+    })
+}
+
+/// The items whose index is set in `mask`.
+pub fn select<T>(mask: u64, items: &[T]) -> impl Iterator<Item = &T> {
+    ones(mask).map(|i| &items[i])
+}
+
+/// Every mask of `n` bits with exactly `k` of them set in increasing order, using Gosper's hack.
+pub fn k_subsets(n: u32, k: u32) -> impl Iterator<Item = u64> {
+    assert!(
+        n <= 64,
+        "k_subsets supports at most 64 bits, use BitSet::next_k_subset"
+    );
+    let end = 1u128 << n;
+    let mut next = (k <= n).then(|| (1u128 << k) - 1);
+
+    std::iter::from_fn(move || {
+        let mask = next?;
+        next = if mask == 0 {
+            None
+        } else {
+            let low = mask & mask.wrapping_neg();
+            let ripple = mask + low;
+            Some((((ripple ^ mask) >> 2) / low) | ripple).filter(|&next| next < end)
+        };
+        Some(mask as u64)
+    })
+}
+
+/// Every submask of `mask` in decreasing order, from `mask` itself down to `0`.
+pub fn submasks(mask: u64) -> impl Iterator<Item = u64> {
+    let mut next = Some(mask);
+    std::iter::from_fn(move || {
+        let sub = next?;
+        next = (sub != 0).then(|| (sub - 1) & mask);
+        Some(sub)
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{BitSet, FixedBitSet, k_subsets, ones, select, submasks};
+
+    #[test]
+    fn grows_on_insert() {
+        let mut set: BitSet = BitSet::new();
+        assert!(set.insert(3));
+        assert!(!set.insert(3));
+        assert!(set.insert(130));
+        set.toggle(64);
+        set.toggle(3);
+
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![64, 130]);
+        assert_eq!((set.len(), set.capacity()), (2, 192));
+        assert!(set.contains(130) && !set.contains(3) && !set.contains(1000));
+        assert!(set.remove(64));
+        assert_eq!(set.first(), Some(130));
+        assert_eq!(set.rank(130), 0);
+        assert_eq!(set.rank(131), 1);
+    }
+
+    #[test]
+    fn updates_ranges() {
+        let mut set: FixedBitSet<3> = FixedBitSet::new();
+        set.insert_range(60..130);
+        set.remove_range(62..128);
+        set.toggle_range(127..129);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![60, 61, 127, 129]);
+        assert_eq!(set.rank(128), 3);
+
+        set.insert_range(5..5);
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "a FixedBitSet<1> only holds bits below 64")]
+    fn fixed_sets_do_not_grow() {
+        let mut set: FixedBitSet<1> = FixedBitSet::new();
+        set.insert(100);
+    }
+
+    #[test]
+    fn combines_sets() {
+        let mut a: BitSet = [1, 5, 70].into_iter().collect();
+        let b: FixedBitSet<1> = [5, 6].into_iter().collect();
+
+        assert!(!a.is_disjoint(&b));
+        a.union_with(&b);
+        assert_eq!(a.iter().collect::<Vec<_>>(), vec![1, 5, 6, 70]);
+        assert!(b.is_subset(&a) && !a.is_subset(&b));
+
+        a.difference_with(&b);
+        assert_eq!(format!("{a:?}"), "{1, 70}");
+        a.intersect_with(&b);
+        assert!(a.is_empty());
+    }
+
+    #[test]
+    fn enumerates_k_subsets() {
+        assert_eq!(
+            k_subsets(4, 2).collect::<Vec<_>>(),
+            vec![0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100]
+        );
+        assert_eq!(k_subsets(3, 0).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(k_subsets(2, 3).count(), 0);
+        assert_eq!(k_subsets(64, 64).collect::<Vec<_>>(), vec![u64::MAX]);
+        assert_eq!(k_subsets(64, 2).count(), 64 * 63 / 2);
+
+        let mut subset: BitSet = BitSet::new();
+        subset.insert_range(0..2);
+        let mut subsets = vec![];
+        loop {
+            subsets.push(subset.iter().map(|i| 1 << i).sum::<u64>());
+            if !subset.next_k_subset(4) {
+                break;
+            }
+        }
+        assert_eq!(subsets, k_subsets(4, 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn enumerates_k_subsets_past_64_bits() {
+        let mut subset: BitSet = BitSet::new();
+        subset.insert_range(0..2);
+        let mut count = 1;
+        while subset.next_k_subset(100) {
+            count += 1;
+        }
+        assert_eq!(count, 100 * 99 / 2);
+        assert_eq!(subset.iter().collect::<Vec<_>>(), vec![98, 99]);
+    }
+
+    #[test]
+    fn enumerates_submasks_and_bits() {
+        assert_eq!(
+            submasks(0b101).collect::<Vec<_>>(),
+            vec![0b101, 0b100, 0b001, 0]
+        );
+        assert_eq!(submasks(0).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(ones(0b1010_0001).collect::<Vec<_>>(), vec![0, 5, 7]);
+        assert_eq!(
+            select(0b110, &["a", "b", "c"]).collect::<Vec<_>>(),
+            vec![&"b", &"c"]
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
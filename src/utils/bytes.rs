@@ -0,0 +1,133 @@
+//! Module with fast parsing helpers that work on the raw bytes of an input, skipping UTF-8 validation and `str::parse`.
+//! They neither allocate nor check for overflow, so they suit benchmark-sensitive days with well-formed inputs.
+
+/// An integer type that can be parsed from ASCII digits.
+pub trait Integer: Copy {
+    const ZERO: Self;
+    const SIGNED: bool;
+
+    /// Appends a digit, i.e. `self * 10 + digit`.
+    fn push_digit(self, digit: u8) -> Self;
+
+    fn negate(self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($signed:literal: $($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const SIGNED: bool = $signed;
+
+                #[inline(always)]
+                fn push_digit(self, digit: u8) -> Self {
+                    self * 10 + digit as $t
+                }
+
+                #[inline(always)]
+                fn negate(self) -> Self {
+                    self.wrapping_neg()
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(false: u8, u16, u32, u64, u128, usize);
+impl_integer!(true: i8, i16, i32, i64, i128, isize);
+
+/// The non-empty lines of `input`, without line endings.
+pub fn lines(input: &[u8]) -> impl Iterator<Item = &[u8]> {
+    input
+        .split(|&b| b == b'\n')
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .filter(|line| !line.is_empty())
+}
+
+/// Parses a token that consists only of digits, with a leading `-` for signed types.
+pub fn parse<T: Integer>(token: &[u8]) -> Option<T> {
+    let (negative, digits) = match token {
+        [b'-', rest @ ..] if T::SIGNED => (true, rest),
+        _ => (false, token),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+
+    let mut value = T::ZERO;
+    for &b in digits {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        value = value.push_digit(b - b'0');
+    }
+    Some(if negative { value.negate() } else { value })
+}
+
+/// Every integer in `input`, like [`integers`](crate::utils::input::integers).
+/// A `-` is only read as a sign for signed types, for unsigned types it separates integers.
+pub fn integers<T: Integer>(input: &[u8]) -> impl Iterator<Item = T> {
+    let mut i = 0;
+
+    std::iter::from_fn(move || {
+        while i < input.len() {
+            let b = input[i];
+            let is_sign = T::SIGNED
+                && b == b'-'
+                && input.get(i + 1).is_some_and(u8::is_ascii_digit)
+                && (i == 0 || !input[i - 1].is_ascii_alphanumeric());
+
+            if is_sign || b.is_ascii_digit() {
+                i += usize::from(is_sign);
+                let mut value = T::ZERO;
+                while i < input.len() && input[i].is_ascii_digit() {
+                    value = value.push_digit(input[i] - b'0');
+                    i += 1;
+                }
+                return Some(if is_sign { value.negate() } else { value });
+            }
+            i += 1;
+        }
+        None
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{integers, lines, parse};
+
+    #[test]
+    fn parses_tokens() {
+        assert_eq!(parse::<u32>(b"1234"), Some(1234));
+        assert_eq!(parse::<i64>(b"-42"), Some(-42));
+        assert_eq!(parse::<u8>(b"-42"), None);
+        assert_eq!(parse::<i32>(b"4x2"), None);
+        assert_eq!(parse::<i32>(b"-"), None);
+    }
+
+    #[test]
+    fn extracts_integers() {
+        let input = b"x=-3, y=4..-12, 10-20";
+        assert_eq!(
+            integers::<i64>(input).collect::<Vec<_>>(),
+            vec![-3, 4, -12, 10, 20]
+        );
+        assert_eq!(
+            integers::<u64>(input).collect::<Vec<_>>(),
+            vec![3, 4, 12, 10, 20]
+        );
+    }
+
+    #[test]
+    fn splits_lines() {
+        let input = b"2x3x4\r\n\n1x1x10\n";
+        assert_eq!(
+            lines(input).collect::<Vec<_>>(),
+            vec![&b"2x3x4"[..], &b"1x1x10"[..]]
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
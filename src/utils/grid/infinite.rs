@@ -0,0 +1,166 @@
+/// Module with a grid that grows in every direction, stored as dense square chunks that are allocated on first write.
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+
+use crate::utils::grid::Point;
+
+/// The width and height of a chunk.
+const CHUNK_SIZE: i64 = 16;
+
+#[derive(Clone, Debug)]
+pub struct InfiniteGrid<T> {
+    chunks: HashMap<Point, Vec<T>>,
+    fill: T,
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T: Clone> InfiniteGrid<T> {
+    /// Creates a grid where every cell starts out as `fill`.
+    pub fn new(fill: T) -> Self {
+        Self {
+            chunks: HashMap::new(),
+            fill,
+            bounds: None,
+        }
+    }
+
+    pub fn get(&self, p: Point) -> &T {
+        let (chunk, offset) = Self::locate(p);
+        self.chunks
+            .get(&chunk)
+            .map_or(&self.fill, |cells| &cells[offset])
+    }
+
+    /// Returns a cell for writing, which extends the bounds to include it.
+    pub fn get_mut(&mut self, p: Point) -> &mut T {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            ),
+            None => (p, p),
+        });
+
+        let (chunk, offset) = Self::locate(p);
+        let fill = &self.fill;
+        let cells = self
+            .chunks
+            .entry(chunk)
+            .or_insert_with(|| vec![fill.clone(); (CHUNK_SIZE * CHUNK_SIZE) as usize]);
+        &mut cells[offset]
+    }
+
+    pub fn set(&mut self, p: Point, value: T) {
+        *self.get_mut(p) = value;
+    }
+
+    /// The smallest and largest corner of the box containing every cell that was written.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.bounds.is_some_and(|(min, max)| {
+            (min.x..=max.x).contains(&p.x) && (min.y..=max.y).contains(&p.y)
+        })
+    }
+
+    /// Iterates over the cells of all allocated chunks in no particular order, including unwritten cells of those chunks.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.chunks.iter().flat_map(|(chunk, cells)| {
+            cells.iter().enumerate().map(move |(i, cell)| {
+                let i = i as i64;
+                let p = Point::new(
+                    chunk.x * CHUNK_SIZE + i % CHUNK_SIZE,
+                    chunk.y * CHUNK_SIZE + i / CHUNK_SIZE,
+                );
+                (p, cell)
+            })
+        })
+    }
+
+    /// Renders the cells within the bounds row by row, top to bottom.
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        let Some((min, max)) = self.bounds else {
+            return String::new();
+        };
+
+        (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| f(self.get(Point::new(x, y))))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The chunk of a point and the index of the point within it.
+    fn locate(p: Point) -> (Point, usize) {
+        let chunk = Point::new(p.x.div_euclid(CHUNK_SIZE), p.y.div_euclid(CHUNK_SIZE));
+        let offset = p.y.rem_euclid(CHUNK_SIZE) * CHUNK_SIZE + p.x.rem_euclid(CHUNK_SIZE);
+        (chunk, offset as usize)
+    }
+}
+
+impl<T: Clone + Default> Default for InfiniteGrid<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Clone> Index<Point> for InfiniteGrid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+    }
+}
+
+impl<T: Clone> IndexMut<Point> for InfiniteGrid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_mut(p)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::InfiniteGrid;
+    use crate::utils::grid::Point;
+
+    #[test]
+    fn grows_in_every_direction() {
+        let mut grid = InfiniteGrid::new(0);
+        grid[Point::new(-20, 3)] += 1;
+        grid[Point::new(40, -17)] += 2;
+        grid[Point::new(-20, 3)] += 1;
+
+        assert_eq!(grid[Point::new(-20, 3)], 2);
+        assert_eq!(grid[Point::new(40, -17)], 2);
+        assert_eq!(grid[Point::new(1000, 1000)], 0);
+        assert_eq!(
+            grid.bounds(),
+            Some((Point::new(-20, -17), Point::new(40, 3)))
+        );
+        assert!(grid.contains(Point::ORIGIN));
+        assert_eq!(grid.iter().filter(|&(_, &n)| n > 0).count(), 2);
+    }
+
+    #[test]
+    fn renders_within_bounds() {
+        let mut grid = InfiniteGrid::new(false);
+        assert_eq!(grid.render(|_| '#'), "");
+
+        for p in [(-1, -1), (1, 0), (0, 1)] {
+            grid.set(p.into(), true);
+        }
+        assert_eq!(
+            grid.render(|&on| if on { '#' } else { '.' }),
+            "#..\n..#\n.#."
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,406 @@
+/// Module with a fixed-size 2D grid over flat storage, positions are `(x, y)` with `x` the column and `y` the row.
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::utils::ParseError;
+
+mod infinite;
+mod point;
+
+pub use infinite::*;
+pub use point::*;
+
+const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid filled with `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a grid from cells in row-major order, panics if they do not fill whole rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not fill rows of width {width}",
+            cells.len()
+        );
+        Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cells in row-major order.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn in_bounds(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.in_bounds(x, y).then(|| &self.cells[self.idx(x, y)])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.in_bounds(x, y) {
+            let idx = self.idx(x, y);
+            Some(&mut self.cells[idx])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a cell without bounds checks, for hot loops that already stay within the grid.
+    ///
+    /// # Safety
+    ///
+    /// `x` must be less than the width and `y` less than the height.
+    pub unsafe fn get_unchecked(&self, x: usize, y: usize) -> &T {
+        debug_assert!(self.in_bounds(x, y));
+        // SAFETY: the caller guarantees that the position is within the grid.
+        unsafe { self.cells.get_unchecked(self.idx(x, y)) }
+    }
+
+    /// Returns a cell without bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// `x` must be less than the width and `y` less than the height.
+    pub unsafe fn get_unchecked_mut(&mut self, x: usize, y: usize) -> &mut T {
+        debug_assert!(self.in_bounds(x, y));
+        let idx = self.idx(x, y);
+        // SAFETY: the caller guarantees that the position is within the grid.
+        unsafe { self.cells.get_unchecked_mut(idx) }
+    }
+
+    /// Iterates over all positions and their cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// The position of the first cell matching `predicate` in row-major order.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| (i % self.width, i / self.width))
+    }
+
+    /// The up to 4 orthogonally adjacent positions within the grid, clockwise starting north.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.offset_positions(x, y, &OFFSETS_4)
+    }
+
+    /// The up to 8 adjacent positions within the grid including diagonals, clockwise starting north-west.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.offset_positions(x, y, &OFFSETS_8)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} is outside a grid of width {}",
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Creates a grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |x, y| (y, self.height - 1 - x))
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise.
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |x, y| (self.width - 1 - y, x))
+    }
+
+    /// Creates a grid of the given size, taking the cell for `(x, y)` from position `source(x, y)`.
+    fn rearranged(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    fn offset_positions(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let nx = x.checked_add_signed(dx).filter(|&nx| nx < width)?;
+            let ny = y.checked_add_signed(dy).filter(|&ny| ny < height)?;
+            Some((nx, ny))
+        })
+    }
+
+    #[inline(always)]
+    fn idx(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    /// Creates a grid from rows, e.g. of [`digit_grid`](crate::utils::input::digit_grid), panics if they differ in length.
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "rows of a grid must have the same length"
+        );
+        Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "position ({x}, {y}) is outside a {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("position ({x}, {y}) is outside a {width}x{height} grid"))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    /// Parses the non-empty lines of `s` as rows of characters, which must all have the same width.
+    /// Spaces are kept, as they can be cells of the grid, only a `\r` left of a line ending is removed.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for line in s
+            .lines()
+            .map(|l| l.strip_suffix('\r').unwrap_or(l))
+            .filter(|l| !l.is_empty())
+        {
+            let len = line.chars().count();
+            match width {
+                Some(width) if width != len => {
+                    return Err(ParseError::new(s, line, format!("row of width {width}")));
+                }
+                _ => width = Some(len),
+            }
+            cells.extend(line.chars());
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Grid;
+
+    fn example() -> Grid<char> {
+        "abc\ndef\n".parse().unwrap()
+    }
+
+    #[test]
+    fn parses_and_displays_char_grids() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let error = "abc\nde\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected row of width 3, found `de`"
+        );
+    }
+
+    #[test]
+    fn keeps_spaces_in_char_grids() {
+        let grid: Grid<char> = " b \nd f\n\n".parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(0, 0)], ' ');
+        assert_eq!(grid[(2, 0)], ' ');
+        assert_eq!(grid.to_string(), " b \nd f");
+
+        // a file saved on Windows, whose last line ending lost its `\n`.
+        let crlf: Grid<char> = " b \r\nd f\r\n\r\n e \r".parse().unwrap();
+        assert_eq!((crlf.width(), crlf.height()), (3, 3));
+        assert_eq!(crlf.to_string(), " b \nd f\n e ");
+    }
+
+    #[test]
+    fn finds_neighbors() {
+        let grid = example();
+        let n4: Vec<_> = grid.neighbors4(0, 0).collect();
+        assert_eq!(n4, vec![(1, 0), (0, 1)]);
+
+        let n8: Vec<_> = grid.neighbors8(1, 1).collect();
+        assert_eq!(n8, vec![(0, 0), (1, 0), (2, 0), (2, 1), (0, 1)]);
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let grid = example();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+        assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn rotates_and_transposes() {
+        let grid = example();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+
+    #[test]
+    fn converts_nested_rows() {
+        let grid = Grid::from(vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(grid.cells(), &[1, 2, 3, 4]);
+        assert_eq!(grid.map(|n| n * 10)[(1, 1)], 40);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
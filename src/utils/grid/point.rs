@@ -0,0 +1,245 @@
+/// Module with points and directions on an unbounded plane, `y` grows downwards like the rows of a [`Grid`](super::Grid).
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::utils::ParseError;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The 4 orthogonally adjacent points, clockwise starting up.
+    pub fn neighbors4(self) -> [Point; 4] {
+        Direction::ALL.map(|d| self + d)
+    }
+
+    /// The 8 adjacent points including diagonals, clockwise starting up-left.
+    pub fn neighbors8(self) -> [Point; 8] {
+        [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+        ]
+        .map(|(dx, dy)| self + Point::new(dx, dy))
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, direction: Direction) -> Point {
+        self + direction.offset()
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The step of one unit in this direction.
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Parses every character of `text` other than whitespace as a direction, e.g. a path like `^>v<`.
+    pub fn parse_sequence(text: &str) -> Result<Vec<Direction>, ParseError> {
+        text.char_indices()
+            .filter(|(_, c)| !c.is_whitespace())
+            .map(|(i, c)| {
+                let token = &text[i..i + c.len_utf8()];
+                token
+                    .parse()
+                    .map_err(|_| ParseError::expected_type::<Direction>(text, token))
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    /// Parses an arrow `^>v<`, a compass point `NESW` or a letter of `URDL`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "^" | "N" | "U" => Ok(Direction::Up),
+            ">" | "E" | "R" => Ok(Direction::Right),
+            "v" | "S" | "D" => Ok(Direction::Down),
+            "<" | "W" | "L" => Ok(Direction::Left),
+            _ => Err(format!(
+                "unknown direction `{s}`, expected one of `^>v<`, `NESW` or `URDL`"
+            )),
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let arrow = match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        };
+        write!(f, "{arrow}")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction, Point};
+
+    #[test]
+    fn does_point_arithmetic() {
+        let p = Point::new(2, -3);
+        assert_eq!(p + Point::new(1, 1), Point::new(3, -2));
+        assert_eq!(p - Point::new(1, 1), Point::new(1, -4));
+        assert_eq!(-p * 2, Point::new(-4, 6));
+        assert_eq!(p + Direction::Up, Point::new(2, -4));
+        assert_eq!(p.manhattan(Point::ORIGIN), 5);
+        assert_eq!(Point::ORIGIN.neighbors4()[1], Point::new(1, 0));
+    }
+
+    #[test]
+    fn turns_directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Right.reverse(), Direction::Left);
+    }
+
+    #[test]
+    fn parses_directions() {
+        assert_eq!(
+            Direction::parse_sequence("^>v<"),
+            Ok(vec![
+                Direction::Up,
+                Direction::Right,
+                Direction::Down,
+                Direction::Left
+            ])
+        );
+        assert_eq!("N".parse(), Ok(Direction::Up));
+        assert_eq!("L".parse(), Ok(Direction::Left));
+
+        let error = Direction::parse_sequence("^^x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: expected Direction, found `x`"
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -1,9 +1,41 @@
 // Advent of Code parsing helpers.
 // All functions take `input: &str` and return parsed structures.
 
-use std::fmt::Debug;
+// Helpers that parse values have a `try_` variant returning a `ParseError` with the position of the
+// offending token. The others panic with that error, which the runner renders as a snippet of the input.
+
+use std::panic::panic_any;
 use std::str::FromStr;
 
+use crate::utils::ParseError;
+
+/// Unwraps a parse result, e.g. of [`scan!`](crate::scan), panicking with the `ParseError` as payload.
+#[track_caller]
+pub fn or_panic<T>(result: Result<T, ParseError>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(error) => panic_any(error),
+    }
+}
+
+/// Parses a token, which must be a slice of `input`.
+fn parse_token<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::expected_type::<T>(input, token))
+}
+
+/// Parses a line of `input`, keeping the position of a [`ParseError`] returned by `FromStr`,
+/// e.g. by a type that derives [`AocParse`](crate::utils::AocParse).
+fn parse_line<T: FromStr<Err: 'static>>(input: &str, line: &str) -> Result<T, ParseError> {
+    line.parse().map_err(|error: T::Err| {
+        match (&error as &dyn std::any::Any).downcast_ref::<ParseError>() {
+            Some(error) => error.clone().within(input, line),
+            None => ParseError::expected_type::<T>(input, line),
+        }
+    })
+}
+
 /// Return lines as Vec<&str> (no empty final line).
 pub fn lines(input: &str) -> Vec<&str> {
     input
@@ -19,15 +51,18 @@ pub fn map_lines<T>(input: &str, f: impl Fn(&str) -> T) -> Vec<T> {
 }
 
 /// Generic, works with any type that implements FromStr
-pub fn parse_lines<T: FromStr>(input: &str) -> Vec<T>
-where
-    <T as FromStr>::Err: Debug,
-{
+#[track_caller]
+pub fn parse_lines<T: FromStr<Err: 'static>>(input: &str) -> Vec<T> {
+    or_panic(try_parse_lines(input))
+}
+
+/// Like [`parse_lines`], but returns an error for the first line that does not parse.
+pub fn try_parse_lines<T: FromStr<Err: 'static>>(input: &str) -> Result<Vec<T>, ParseError> {
     input
         .lines()
         .map(|line| line.trim()) // Handle potential carriage returns or spaces
         .filter(|line| !line.is_empty()) // Skip empty lines at end of file
-        .map(|line| line.parse::<T>().expect("Failed to parse line"))
+        .map(|line| parse_line(input, line))
         .collect()
 }
 
@@ -41,46 +76,67 @@ pub fn split_lines(input: &str, sep: char) -> Vec<Vec<&str>> {
 }
 
 /// Loop over lines and split a line and parse each element using FromStr.
-pub fn split_parse_lines<T: FromStr>(input: &str, sep: char) -> Vec<Vec<T>>
-where
-    <T as FromStr>::Err: Debug,
-{
+#[track_caller]
+pub fn split_parse_lines<T: FromStr>(input: &str, sep: char) -> Vec<Vec<T>> {
+    or_panic(try_split_parse_lines(input, sep))
+}
+
+/// Like [`split_parse_lines`], but returns an error for the first element that does not parse.
+pub fn try_split_parse_lines<T: FromStr>(
+    input: &str,
+    sep: char,
+) -> Result<Vec<Vec<T>>, ParseError> {
     input
         .lines()
         .filter(|l| !l.is_empty())
         .map(|l| {
             l.split(sep)
                 .filter(|s| !s.is_empty())
-                .map(|s| s.trim().parse::<T>().expect("parse error"))
+                .map(|s| parse_token(input, s.trim()))
                 .collect()
         })
         .collect()
 }
 
 /// Single delimiter-separated line of i64 values.
+#[track_caller]
 pub fn split_line_i64(line: &str, sep: char) -> Vec<i64> {
+    or_panic(try_split_line_i64(line, sep))
+}
+
+/// Like [`split_line_i64`], errors are positioned relative to `line`.
+pub fn try_split_line_i64(line: &str, sep: char) -> Result<Vec<i64>, ParseError> {
     line.trim()
         .split(sep)
         .filter(|s| !s.is_empty())
-        .map(|s| s.parse::<i64>().expect("invalid integer"))
+        .map(|s| parse_token(line, s))
         .collect()
 }
 
 /// Single whitespace-separated line of i64 values.
+#[track_caller]
 pub fn whitespace_line_i64(line: &str) -> Vec<i64> {
+    or_panic(try_whitespace_line_i64(line))
+}
+
+/// Like [`whitespace_line_i64`], errors are positioned relative to `line`.
+pub fn try_whitespace_line_i64(line: &str) -> Result<Vec<i64>, ParseError> {
     line.split_whitespace()
-        .map(|s| s.parse::<i64>().expect("invalid integer"))
+        .map(|s| parse_token(line, s))
         .collect()
 }
 
 /// Split a line and parse each element using FromStr.
-pub fn split_line_parse<T: FromStr>(line: &str, sep: char) -> Vec<T>
-where
-    <T as FromStr>::Err: Debug,
-{
+#[track_caller]
+pub fn split_line_parse<T: FromStr>(line: &str, sep: char) -> Vec<T> {
+    or_panic(try_split_line_parse(line, sep))
+}
+
+/// Like [`split_line_parse`], errors are positioned relative to `line`.
+pub fn try_split_line_parse<T: FromStr>(line: &str, sep: char) -> Result<Vec<T>, ParseError> {
     line.split(sep)
         .filter(|s| !s.is_empty())
-        .map(|s| s.parse::<T>().expect("parse error"))
+        .map(|s| parse_token(line, s))
         .collect()
 }
 
@@ -94,20 +150,40 @@ pub fn char_grid(input: &str) -> Vec<Vec<char>> {
 }
 
 /// Grid of single-digit integers.
+#[track_caller]
 pub fn digit_grid(input: &str) -> Vec<Vec<u8>> {
+    or_panic(try_digit_grid(input))
+}
+
+/// Like [`digit_grid`], but returns an error for the first character that is not a digit.
+pub fn try_digit_grid(input: &str) -> Result<Vec<Vec<u8>>, ParseError> {
     input
         .lines()
-        .map(|l| l.chars().map(|c| c as u8 - b'0').collect())
+        .map(|l| {
+            l.char_indices()
+                .map(|(i, c)| match c.to_digit(10) {
+                    #[allow(clippy::cast_possible_truncation)]
+                    Some(digit) => Ok(digit as u8),
+                    None => Err(ParseError::new(input, &l[i..i + c.len_utf8()], "digit")),
+                })
+                .collect()
+        })
         .collect()
 }
 
 /// Grid of integers separated by whitespace.
+#[track_caller]
 pub fn int_grid(input: &str) -> Vec<Vec<i32>> {
+    or_panic(try_int_grid(input))
+}
+
+/// Like [`int_grid`], but returns an error for the first integer that does not parse.
+pub fn try_int_grid(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
     input
         .lines()
         .map(|l| {
             l.split_whitespace()
-                .map(|x| x.parse::<i32>().unwrap())
+                .map(|x| parse_token(input, x))
                 .collect()
         })
         .collect()
@@ -136,16 +212,19 @@ pub fn blocks_of_lines(input: &str) -> Vec<Vec<&str>> {
 }
 
 /// Blocks where each line is parsed..
-pub fn parse_blocks<T: FromStr>(input: &str) -> Vec<Vec<T>>
-where
-    <T as FromStr>::Err: Debug,
-{
+#[track_caller]
+pub fn parse_blocks<T: FromStr>(input: &str) -> Vec<Vec<T>> {
+    or_panic(try_parse_blocks(input))
+}
+
+/// Like [`parse_blocks`], but returns an error for the first line that does not parse.
+pub fn try_parse_blocks<T: FromStr>(input: &str) -> Result<Vec<Vec<T>>, ParseError> {
     input
         .split("\n\n")
         .map(|b| {
             b.lines()
                 .filter(|l| !l.is_empty())
-                .map(|l| l.trim().parse::<T>().expect("invalid integer"))
+                .map(|l| parse_token(input, l.trim()))
                 .collect()
         })
         .collect()
@@ -156,34 +235,340 @@ where
 // ------------------------------------------------------------
 
 /// Parses an input where coordinates are listed as "x,y" on lines.
-pub fn parse_coordinate_pairs<T: FromStr>(input: &str, sep: char) -> Vec<(T, T)>
-where
-    <T as FromStr>::Err: Debug,
-{
+#[track_caller]
+pub fn parse_coordinate_pairs<T: FromStr>(input: &str, sep: char) -> Vec<(T, T)> {
+    or_panic(try_parse_coordinate_pairs(input, sep))
+}
+
+/// Like [`parse_coordinate_pairs`], but returns an error for the first line that does not parse.
+pub fn try_parse_coordinate_pairs<T: FromStr>(
+    input: &str,
+    sep: char,
+) -> Result<Vec<(T, T)>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let (x, y) = line.split_once(sep).expect("Line must contain a comma");
-            (
-                x.trim().parse::<T>().expect("Failed to parse X"),
-                y.trim().parse::<T>().expect("Failed to parse Y"),
-            )
+            let (x, y) = line
+                .split_once(sep)
+                .ok_or_else(|| ParseError::new(input, &line[line.len()..], format!("`{sep}`")))?;
+            Ok((parse_token(input, x.trim())?, parse_token(input, y.trim())?))
         })
         .collect()
 }
 
 /// Split a line `"a,b"` into (a, b) as i64.
+#[track_caller]
 pub fn split_i64_pair(line: &str, sep: char) -> (i64, i64) {
+    or_panic(try_split_i64_pair(line, sep))
+}
+
+/// Like [`split_i64_pair`], errors are positioned relative to `line`.
+pub fn try_split_i64_pair(line: &str, sep: char) -> Result<(i64, i64), ParseError> {
     let mut it = line.split(sep);
-    let a = it.next().unwrap().parse().unwrap();
-    let b = it.next().unwrap().parse().unwrap();
-    (a, b)
+    let mut next = || {
+        it.next()
+            .ok_or_else(|| ParseError::new(line, &line[line.len()..], format!("`{sep}`")))
+            .and_then(|s| parse_token(line, s))
+    };
+    Ok((next()?, next()?))
 }
 
 /// Split a line `"x1 y1"` etc. into Vec<i64>.
+#[track_caller]
 pub fn split_to_i64(line: &str, sep: char) -> Vec<i64> {
+    or_panic(try_split_to_i64(line, sep))
+}
+
+/// Like [`split_to_i64`], errors are positioned relative to `line`.
+pub fn try_split_to_i64(line: &str, sep: char) -> Result<Vec<i64>, ParseError> {
     line.split(sep)
         .filter(|s| !s.is_empty())
-        .map(|s| s.parse::<i64>().unwrap())
+        .map(|s| parse_token(line, s))
         .collect()
 }
+
+// ------------------------------------------------------------
+// Lazy iterators, for callers that only iterate once
+// ------------------------------------------------------------
+
+/// Like [`lines`], without collecting.
+pub fn lines_iter(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().filter(|l| !l.is_empty()).map(|l| l.trim())
+}
+
+/// Like [`parse_lines`], without collecting. Panics when reaching a line that does not parse.
+pub fn parse_lines_iter<T: FromStr<Err: 'static>>(input: &str) -> impl Iterator<Item = T> {
+    input
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(move |line| or_panic(parse_line(input, line)))
+}
+
+/// Like [`split_lines`], without collecting.
+pub fn split_lines_iter(
+    input: &str,
+    sep: char,
+) -> impl Iterator<Item = impl Iterator<Item = &str>> {
+    input.lines().map(move |l| l.split(sep))
+}
+
+/// Like [`split_parse_lines`], without collecting. Panics when reaching an element that does not parse.
+pub fn split_parse_lines_iter<T: FromStr>(
+    input: &str,
+    sep: char,
+) -> impl Iterator<Item = impl Iterator<Item = T>> {
+    input.lines().filter(|l| !l.is_empty()).map(move |l| {
+        l.split(sep)
+            .filter(|s| !s.is_empty())
+            .map(move |s| or_panic(parse_token(input, s.trim())))
+    })
+}
+
+/// Like [`blocks`], without collecting.
+pub fn blocks_iter(input: &str) -> impl Iterator<Item = &str> {
+    input.split("\n\n")
+}
+
+/// Like [`parse_coordinate_pairs`], without collecting. Panics when reaching a line that does not parse.
+pub fn parse_coordinate_pairs_iter<T: FromStr>(
+    input: &str,
+    sep: char,
+) -> impl Iterator<Item = (T, T)> {
+    input.lines().map(move |line| {
+        let (x, y) = line.split_once(sep).unwrap_or_else(|| {
+            or_panic(Err(ParseError::new(
+                input,
+                &line[line.len()..],
+                format!("`{sep}`"),
+            )))
+        });
+        (
+            or_panic(parse_token(input, x.trim())),
+            or_panic(parse_token(input, y.trim())),
+        )
+    })
+}
+
+// ------------------------------------------------------------
+// Integers in free-form text
+// ------------------------------------------------------------
+
+/// Every integer in `text`, e.g. `[14, 10, 127]` for "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.".
+/// A `-` is a sign unless it follows a letter or digit, so "10-20" yields `[10, 20]` and "x=-3" yields `[-3]`.
+pub fn integers<T: FromStr>(text: &str) -> impl Iterator<Item = T> {
+    try_integers(text).map(or_panic)
+}
+
+/// Like [`integers`], but yields an error for integers that do not fit in `T`.
+pub fn try_integers<T: FromStr>(text: &str) -> impl Iterator<Item = Result<T, ParseError>> {
+    integer_tokens(text).map(|token| parse_token(text, token))
+}
+
+/// Every integer in `text` as a Vec.
+#[track_caller]
+pub fn integer_vec<T: FromStr>(text: &str) -> Vec<T> {
+    or_panic(try_integer_vec(text))
+}
+
+/// Like [`integer_vec`], but returns an error for integers that do not fit in `T`.
+pub fn try_integer_vec<T: FromStr>(text: &str) -> Result<Vec<T>, ParseError> {
+    try_integers(text).collect()
+}
+
+/// Exactly `N` integers in `text`, e.g. `let [row, column] = integer_array(input);`.
+#[track_caller]
+pub fn integer_array<T: FromStr, const N: usize>(text: &str) -> [T; N] {
+    or_panic(try_integer_array(text))
+}
+
+/// Like [`integer_array`], but returns an error if `text` does not contain exactly `N` integers.
+pub fn try_integer_array<T: FromStr, const N: usize>(text: &str) -> Result<[T; N], ParseError> {
+    integer_array_in(text, text)
+}
+
+/// The integers of each non-empty line of `input`.
+#[track_caller]
+pub fn integer_lines<T: FromStr>(input: &str) -> Vec<Vec<T>> {
+    or_panic(try_integer_lines(input))
+}
+
+/// Like [`integer_lines`], but returns an error for the first integer that does not fit in `T`.
+pub fn try_integer_lines<T: FromStr>(input: &str) -> Result<Vec<Vec<T>>, ParseError> {
+    input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            integer_tokens(l)
+                .map(|token| parse_token(input, token))
+                .collect()
+        })
+        .collect()
+}
+
+/// Exactly `N` integers of each non-empty line of `input`.
+#[track_caller]
+pub fn integer_array_lines<T: FromStr, const N: usize>(input: &str) -> Vec<[T; N]> {
+    or_panic(try_integer_array_lines(input))
+}
+
+/// Like [`integer_array_lines`], but returns an error for the first line without exactly `N` integers.
+pub fn try_integer_array_lines<T: FromStr, const N: usize>(
+    input: &str,
+) -> Result<Vec<[T; N]>, ParseError> {
+    input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| integer_array_in(input, l))
+        .collect()
+}
+
+/// Parses exactly `N` integers of `text`, which must be a slice of `input`.
+fn integer_array_in<T: FromStr, const N: usize>(
+    input: &str,
+    text: &str,
+) -> Result<[T; N], ParseError> {
+    let expected = || format!("{N} integers");
+    let mut tokens = integer_tokens(text);
+
+    let values: Vec<T> = tokens
+        .by_ref()
+        .take(N)
+        .map(|token| parse_token(input, token))
+        .collect::<Result<_, _>>()?;
+
+    if let Some(extra) = tokens.next() {
+        return Err(ParseError::new(input, extra, expected()));
+    }
+
+    values.try_into().map_err(|_| {
+        let end = text.trim_end().len();
+        ParseError::new(input, &text[end..end], expected())
+    })
+}
+
+/// The slices of `text` that make up integers, see [`integers`].
+fn integer_tokens(text: &str) -> impl Iterator<Item = &str> {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+
+    std::iter::from_fn(move || {
+        while i < bytes.len() {
+            let start = i;
+            let is_sign = bytes[i] == b'-'
+                && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+                && (i == 0 || !bytes[i - 1].is_ascii_alphanumeric());
+
+            if is_sign || bytes[i].is_ascii_digit() {
+                i += 1;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                return Some(&text[start..i]);
+            }
+            i += 1;
+        }
+        None
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        integer_array_lines, integer_vec, try_digit_grid, try_integer_array,
+        try_integer_array_lines, try_parse_coordinate_pairs, try_parse_lines,
+        try_split_parse_lines,
+    };
+    use crate::utils::AocParse;
+
+    #[test]
+    fn reports_positions_of_invalid_tokens() {
+        let error = try_split_parse_lines::<u64>("2x3x4\n1x1xl0\n", 'x').unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected u64, found `l0`"
+        );
+
+        let error = try_digit_grid("123\n4a6\n").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (2, 2, "a")
+        );
+    }
+
+    #[test]
+    fn keeps_positions_of_derived_errors() {
+        #[derive(Debug, AocParse)]
+        #[aoc(pattern = "{} -> {}")]
+        struct Wire(u16, String);
+
+        let wires = try_parse_lines::<Wire>("123 -> x\n").unwrap();
+        assert_eq!((wires[0].0, wires[0].1.as_str()), (123, "x"));
+
+        let error = try_parse_lines::<Wire>("123 -> x\n  45x -> y\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected u16, found `45x`"
+        );
+
+        let error = try_parse_lines::<u16>("123\n45a\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected u16, found `45a`"
+        );
+    }
+
+    #[test]
+    fn reports_missing_separators() {
+        let error = try_parse_coordinate_pairs::<i32>("1,2\n3 4\n", ',').unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected `,`, found end of line"
+        );
+
+        assert_eq!(
+            try_parse_coordinate_pairs::<i32>("1,2\n-3, 4\n", ','),
+            Ok(vec![(1, 2), (-3, 4)])
+        );
+    }
+
+    #[test]
+    fn extracts_integers() {
+        let line = "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.";
+        assert_eq!(integer_vec::<u64>(line), vec![14, 10, 127]);
+        assert_eq!(
+            integer_vec::<i64>("x=-3, y=4..-12, 10-20"),
+            vec![-3, 4, -12, 10, 20]
+        );
+        assert_eq!(try_integer_array::<u8, 2>("row 3, column 4."), Ok([3, 4]));
+        assert_eq!(
+            integer_array_lines::<i32, 2>("a: 1 b: -2\n\nc: 3 d: 4\n"),
+            vec![[1, -2], [3, 4]]
+        );
+    }
+
+    #[test]
+    fn reports_integer_count_mismatches() {
+        let error = try_integer_array::<u8, 3>("1, 2.\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 6: expected 3 integers, found end of line"
+        );
+
+        let error = try_integer_array_lines::<u8, 2>("1 2\n3 4 5\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected 2 integers, found `5`"
+        );
+
+        let error = try_integer_array::<u8, 1>("300").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected u8, found `300`"
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,150 @@
+/// Module that maps names to dense ids and back, so tables keyed by names can be stored in a `Vec`.
+use std::collections::HashMap;
+
+/// A dense id handed out by an [`Interner`].
+pub trait Id: Copy {
+    fn from_index(index: usize) -> Self;
+
+    fn index(self) -> usize;
+}
+
+macro_rules! impl_id {
+    ($($t:ty),*) => {
+        $(
+            impl Id for $t {
+                fn from_index(index: usize) -> Self {
+                    <$t>::try_from(index).expect("too many names for the id type")
+                }
+
+                fn index(self) -> usize {
+                    self as usize
+                }
+            }
+        )*
+    };
+}
+
+impl_id!(u8, u16, u32, usize);
+
+/// Hands out ids `0, 1, 2, ...` to names in the order they are first seen.
+/// Names are borrowed, e.g. from the input, so interning never copies them.
+///
+/// ```
+/// use advent_of_code::utils::Interner;
+///
+/// let mut wires: Interner<u32> = Interner::new();
+/// let x = wires.intern("x");
+/// assert_eq!(wires.intern("y"), 1);
+/// assert_eq!(wires.intern("x"), x);
+/// assert_eq!(wires.name(x), "x");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Interner<'a, I = usize> {
+    ids: HashMap<&'a str, I>,
+    names: Vec<&'a str>,
+}
+
+impl<'a, I: Id> Interner<'a, I> {
+    pub fn new() -> Self {
+        Self {
+            ids: HashMap::new(),
+            names: vec![],
+        }
+    }
+
+    /// Returns the id of `name`, handing out the next one if it is new.
+    pub fn intern(&mut self, name: &'a str) -> I {
+        *self.ids.entry(name).or_insert_with(|| {
+            self.names.push(name);
+            I::from_index(self.names.len() - 1)
+        })
+    }
+
+    /// Returns the id of `name` if it was interned.
+    pub fn get(&self, name: &str) -> Option<I> {
+        self.ids.get(name).copied()
+    }
+
+    /// Returns the name of an id, panics if it was not handed out by this interner.
+    pub fn name(&self, id: I) -> &'a str {
+        self.names[id.index()]
+    }
+
+    /// The interned names, indexed by id.
+    pub fn names(&self) -> &[&'a str] {
+        &self.names
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Iterates over the ids and their names in order.
+    pub fn iter(&self) -> impl Iterator<Item = (I, &'a str)> {
+        self.names
+            .iter()
+            .enumerate()
+            .map(|(i, &name)| (I::from_index(i), name))
+    }
+}
+
+impl<I: Id> Default for Interner<'_, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, I: Id> FromIterator<&'a str> for Interner<'a, I> {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(names: T) -> Self {
+        let mut interner = Self::new();
+        for name in names {
+            interner.intern(name);
+        }
+        interner
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Interner;
+
+    #[test]
+    fn interns_names_densely() {
+        let input = String::from("London to Dublin\nLondon to Belfast\n");
+        let mut cities: Interner = Interner::new();
+
+        let ids: Vec<usize> = input
+            .split_whitespace()
+            .filter(|&w| w != "to")
+            .map(|city| cities.intern(city))
+            .collect();
+
+        assert_eq!(ids, vec![0, 1, 0, 2]);
+        assert_eq!(cities.len(), 3);
+        assert_eq!(cities.names(), &["London", "Dublin", "Belfast"]);
+        assert_eq!(cities.get("Belfast"), Some(2));
+        assert_eq!(cities.get("Paris"), None);
+    }
+
+    #[test]
+    fn supports_smaller_ids() {
+        let wires: Interner<u16> = ["a", "b", "a"].into_iter().collect();
+        assert_eq!(wires.iter().collect::<Vec<_>>(), vec![(0, "a"), (1, "b")]);
+        assert_eq!(wires.name(1), "b");
+    }
+
+    #[test]
+    #[should_panic(expected = "too many names for the id type")]
+    fn panics_when_ids_run_out() {
+        let names: Vec<String> = (0..=256).map(|i| i.to_string()).collect();
+        let _: Interner<u8> = names.iter().map(String::as_str).collect();
+    }
+}
+
+/* -------------------------------------------------------------------------- */
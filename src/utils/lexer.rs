@@ -0,0 +1,432 @@
+/// Module that splits instruction-like lines into tokens, and backs the [`mnemonics!`](crate::mnemonics) macro,
+/// which maps patterns of tokens to values such as the variants of an instruction enum.
+use std::ops::Range;
+
+use crate::utils::ParseError;
+
+/// Tries patterns of literals and typed captures against `$line` in order and evaluates the value of the first
+/// one that matches the whole line, returning a `Result` with a [`ParseError`] if none does.
+/// Literals are matched token by token, so `"->"` matches a `-` followed by a `>`, and the words of the literals
+/// are lexed as [`Token::Keyword`]s. A capture `{name: Type}` binds the next token as any [`FromToken`] type.
+/// If every pattern fails, the error is the one furthest into the line, so an unknown opcode is reported with the
+/// opcodes that were expected instead.
+///
+/// ```
+/// use advent_of_code::mnemonics;
+/// # #[derive(Debug, PartialEq)]
+/// # enum Instruction<'a> {
+/// #     Inc(&'a str),
+/// #     Jie(&'a str, i32),
+/// # }
+/// # let line = "jie a, +4";
+///
+/// let instruction = mnemonics!(line, {
+///     ["inc" {r: &str}] => Instruction::Inc(r),
+///     ["jie" {r: &str} "," {offset: i32}] => Instruction::Jie(r, offset),
+/// })?;
+/// assert_eq!(instruction, Instruction::Jie("a", 4));
+/// # Ok::<(), advent_of_code::utils::ParseError>(())
+/// ```
+#[macro_export]
+macro_rules! mnemonics {
+    ($line:expr, { $([$($elem:tt)*] => $value:expr),+ $(,)? }) => {{
+        let line: &str = $line;
+        'mnemonics: {
+            let keywords: &[&str] = $crate::mnemonics!(@keywords [] $($($elem)*)+);
+            let mut tokens = $crate::utils::lexer::Tokens::new(line, keywords);
+            let mut errors = vec![];
+            $(
+                'arm: {
+                    tokens.reset();
+                    $crate::mnemonics!(@arm tokens errors 'arm $($elem)*);
+                    if let Err(error) = tokens.finish() {
+                        errors.push(error);
+                        break 'arm;
+                    }
+                    break 'mnemonics Ok($value);
+                }
+            )+
+            Err($crate::utils::lexer::furthest(errors))
+        }
+    }};
+    (@keywords [$($keyword:literal)*] $literal:literal $($rest:tt)*) => {
+        $crate::mnemonics!(@keywords [$($keyword)* $literal] $($rest)*)
+    };
+    (@keywords [$($keyword:literal)*] { $($capture:tt)* } $($rest:tt)*) => {
+        $crate::mnemonics!(@keywords [$($keyword)*] $($rest)*)
+    };
+    (@keywords [$($keyword:literal)*]) => {
+        &[$($keyword),*]
+    };
+    (@arm $tokens:ident $errors:ident $arm:lifetime) => {};
+    (@arm $tokens:ident $errors:ident $arm:lifetime $literal:literal $($rest:tt)*) => {
+        if let Err(error) = $tokens.expect($literal) {
+            $errors.push(error);
+            break $arm;
+        }
+        $crate::mnemonics!(@arm $tokens $errors $arm $($rest)*);
+    };
+    (@arm $tokens:ident $errors:ident $arm:lifetime { $name:ident : $ty:ty } $($rest:tt)*) => {
+        let $name: $ty = match $tokens.capture() {
+            Ok(value) => value,
+            Err(error) => {
+                $errors.push(error);
+                break $arm;
+            }
+        };
+        $crate::mnemonics!(@arm $tokens $errors $arm $($rest)*);
+    };
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Token<'a> {
+    /// A letter or `_` followed by letters, digits and `_`.
+    Ident(&'a str),
+    /// An identifier that is one of the keywords passed to the lexer.
+    Keyword(&'a str),
+    /// Digits with an optional `-` or `+` sign, which is only a sign if it does not follow a letter or digit.
+    /// The text is parsed by [`FromToken`], so overflow is reported against the type it is read as.
+    Int(&'a str),
+    /// Any other character that is not whitespace.
+    Punct(char),
+}
+
+/// A token with the text it was lexed from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lexeme<'a> {
+    pub token: Token<'a>,
+    /// A slice of the lexed text, so it can be passed to [`ParseError::new`].
+    pub text: &'a str,
+    /// The byte range of `text` in the lexed text.
+    pub span: Range<usize>,
+}
+
+/// Iterates over the tokens of a text, see [`tokenize`].
+pub struct Lexer<'a, 'k> {
+    text: &'a str,
+    keywords: &'k [&'k str],
+    offset: usize,
+}
+
+impl<'a, 'k> Lexer<'a, 'k> {
+    pub fn new(text: &'a str, keywords: &'k [&'k str]) -> Self {
+        Self {
+            text,
+            keywords,
+            offset: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for Lexer<'a, '_> {
+    type Item = Lexeme<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.text.as_bytes();
+        while self.offset < bytes.len() && bytes[self.offset].is_ascii_whitespace() {
+            self.offset += 1;
+        }
+        let start = self.offset;
+        let c = self.text[start..].chars().next()?;
+
+        let is_sign = matches!(c, '-' | '+')
+            && bytes.get(start + 1).is_some_and(u8::is_ascii_digit)
+            && (start == 0 || !bytes[start - 1].is_ascii_alphanumeric());
+        let end = if is_sign || c.is_ascii_digit() {
+            let digits = start + usize::from(is_sign);
+            digits
+                + self.text[digits..]
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(self.text.len() - digits)
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            start
+                + self.text[start..]
+                    .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                    .unwrap_or(self.text.len() - start)
+        } else {
+            start + c.len_utf8()
+        };
+        self.offset = end;
+
+        let text = &self.text[start..end];
+        let token = if is_sign || c.is_ascii_digit() {
+            Token::Int(text)
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            if self.keywords.contains(&text) {
+                Token::Keyword(text)
+            } else {
+                Token::Ident(text)
+            }
+        } else {
+            Token::Punct(c)
+        };
+
+        Some(Lexeme {
+            token,
+            text,
+            span: start..end,
+        })
+    }
+}
+
+/// Splits `text` into tokens, skipping whitespace. Identifiers in `keywords` become [`Token::Keyword`]s.
+pub fn tokenize<'a>(text: &'a str, keywords: &[&str]) -> Vec<Lexeme<'a>> {
+    Lexer::new(text, keywords).collect()
+}
+
+/// A type that can be read from a single token, e.g. by a capture of [`mnemonics!`](crate::mnemonics).
+pub trait FromToken<'a>: Sized {
+    /// Converts `lexeme`, which was lexed from `line`.
+    fn from_token(line: &'a str, lexeme: &Lexeme<'a>) -> Result<Self, ParseError>;
+}
+
+macro_rules! impl_from_token {
+    ($($t:ty),*) => {
+        $(
+            impl<'a> FromToken<'a> for $t {
+                fn from_token(line: &'a str, lexeme: &Lexeme<'a>) -> Result<Self, ParseError> {
+                    match lexeme.token {
+                        Token::Int(digits) => digits.parse().ok(),
+                        _ => None,
+                    }
+                    .ok_or_else(|| ParseError::expected_type::<$t>(line, lexeme.text))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_token!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// An identifier, keywords are not accepted.
+impl<'a> FromToken<'a> for &'a str {
+    fn from_token(line: &'a str, lexeme: &Lexeme<'a>) -> Result<Self, ParseError> {
+        match lexeme.token {
+            Token::Ident(name) => Ok(name),
+            _ => Err(ParseError::new(line, lexeme.text, "identifier")),
+        }
+    }
+}
+
+impl<'a> FromToken<'a> for String {
+    fn from_token(line: &'a str, lexeme: &Lexeme<'a>) -> Result<Self, ParseError> {
+        <&str>::from_token(line, lexeme).map(str::to_string)
+    }
+}
+
+/// Any token.
+impl<'a> FromToken<'a> for Lexeme<'a> {
+    fn from_token(_: &'a str, lexeme: &Lexeme<'a>) -> Result<Self, ParseError> {
+        Ok(lexeme.clone())
+    }
+}
+
+/// The tokens of a line with a cursor, as matched by [`mnemonics!`](crate::mnemonics).
+pub struct Tokens<'a> {
+    line: &'a str,
+    lexemes: Vec<Lexeme<'a>>,
+    position: usize,
+}
+
+impl<'a> Tokens<'a> {
+    /// Lexes `line`, the words of the `literals` are keywords.
+    pub fn new(line: &'a str, literals: &[&str]) -> Self {
+        let keywords: Vec<&str> = literals
+            .iter()
+            .flat_map(|literal| Lexer::new(literal, &[]))
+            .filter_map(|lexeme| match lexeme.token {
+                Token::Ident(word) => Some(word),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            line,
+            lexemes: tokenize(line, &keywords),
+            position: 0,
+        }
+    }
+
+    /// Moves the cursor back to the first token.
+    pub fn reset(&mut self) {
+        self.position = 0;
+    }
+
+    /// Matches the tokens of `literal`, comparing their text.
+    pub fn expect(&mut self, literal: &str) -> Result<(), ParseError> {
+        for expected in Lexer::new(literal, &[]) {
+            match self.lexemes.get(self.position) {
+                Some(lexeme) if lexeme.text == expected.text => self.position += 1,
+                found => {
+                    let found = found.map_or(self.end(), |lexeme| lexeme.text);
+                    return Err(ParseError::new(self.line, found, format!("`{literal}`")));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Converts the next token.
+    pub fn capture<T: FromToken<'a>>(&mut self) -> Result<T, ParseError> {
+        let Some(lexeme) = self.lexemes.get(self.position) else {
+            return Err(ParseError::expected_type::<T>(self.line, self.end()));
+        };
+        self.position += 1;
+        T::from_token(self.line, lexeme)
+    }
+
+    /// Checks that every token was matched.
+    pub fn finish(&self) -> Result<(), ParseError> {
+        match self.lexemes.get(self.position) {
+            None => Ok(()),
+            Some(lexeme) => Err(ParseError::new(
+                self.line,
+                &self.line[lexeme.span.start..],
+                "end of line",
+            )),
+        }
+    }
+
+    fn end(&self) -> &'a str {
+        &self.line[self.line.len()..]
+    }
+}
+
+/// The error that got furthest into the line. Errors at the same position are merged into one that expects any of them.
+pub fn furthest(errors: Vec<ParseError>) -> ParseError {
+    let position = |error: &ParseError| (error.line, error.column);
+    let last = errors
+        .iter()
+        .map(position)
+        .max()
+        .expect("at least one error");
+
+    let mut expected: Vec<String> = vec![];
+    let mut furthest = None;
+    for error in errors.into_iter().filter(|error| position(error) == last) {
+        if !expected.contains(&error.expected) {
+            expected.push(error.expected.clone());
+        }
+        furthest.get_or_insert(error);
+    }
+
+    let mut error = furthest.unwrap();
+    if expected.len() > 1 {
+        error.expected = format!("one of {}", expected.join(", "));
+    }
+    error
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{FromToken, Lexeme, Token, tokenize};
+
+    #[test]
+    fn tokenizes_with_spans() {
+        let tokens = tokenize("jio a, +2", &["jio"]);
+        let kinds: Vec<Token> = tokens.iter().map(|lexeme| lexeme.token).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                Token::Keyword("jio"),
+                Token::Ident("a"),
+                Token::Punct(','),
+                Token::Int("+2")
+            ]
+        );
+        assert_eq!(
+            tokens[3],
+            Lexeme {
+                token: Token::Int("+2"),
+                text: "+2",
+                span: 7..9,
+            }
+        );
+
+        let kinds: Vec<Token> = tokenize("x1-3 -> y_2 -4", &[])
+            .into_iter()
+            .map(|lexeme| lexeme.token)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                Token::Ident("x1"),
+                Token::Punct('-'),
+                Token::Int("3"),
+                Token::Punct('-'),
+                Token::Punct('>'),
+                Token::Ident("y_2"),
+                Token::Int("-4")
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_overflowing_integers() {
+        let line = "18446744073709551615 256";
+        let tokens = tokenize(line, &[]);
+        assert_eq!(u64::from_token(line, &tokens[0]), Ok(u64::MAX));
+
+        let error = u8::from_token(line, &tokens[1]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 22: expected u8, found `256`"
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Instruction<'a> {
+        Inc(&'a str),
+        Jmp(i32),
+        Jie(&'a str, i32),
+        TurnOn(usize, usize),
+    }
+
+    fn parse(line: &str) -> Result<Instruction<'_>, crate::utils::ParseError> {
+        mnemonics!(line, {
+            ["inc" {r: &str}] => Instruction::Inc(r),
+            ["jmp" {offset: i32}] => Instruction::Jmp(offset),
+            ["jie" {r: &str} "," {offset: i32}] => Instruction::Jie(r, offset),
+            ["turn on" {x: usize} "," {y: usize}] => Instruction::TurnOn(x, y),
+        })
+    }
+
+    #[test]
+    fn maps_mnemonics() {
+        assert_eq!(parse("inc a"), Ok(Instruction::Inc("a")));
+        assert_eq!(parse("jmp -7"), Ok(Instruction::Jmp(-7)));
+        assert_eq!(parse("jie b, +4"), Ok(Instruction::Jie("b", 4)));
+        assert_eq!(parse("turn  on 3,4"), Ok(Instruction::TurnOn(3, 4)));
+    }
+
+    #[test]
+    fn reports_unknown_opcodes() {
+        let error = parse("dec a").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected one of `inc`, `jmp`, `jie`, `turn on`, found `dec`"
+        );
+
+        let error = parse("jie b 4").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 7: expected `,`, found `4`"
+        );
+
+        let error = parse("jmp a").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: expected i32, found `a`"
+        );
+
+        let error = parse("inc a b").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 7: expected end of line, found `b`"
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
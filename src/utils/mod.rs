@@ -1 +1,12 @@
+pub mod bits;
+pub mod bytes;
+pub mod grid;
 pub mod input;
+mod interner;
+pub mod lexer;
+mod parse_error;
+pub mod scan;
+
+pub use advent_of_code_derive::AocParse;
+pub use interner::*;
+pub use parse_error::*;
@@ -0,0 +1,202 @@
+/// Module that describes where parsing a puzzle input failed, with a snippet of the offending line.
+use std::any::type_name;
+use std::fmt::{Display, Write};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line of the token, starting at 1.
+    pub line: usize,
+    /// The column of the token in characters, starting at 1.
+    pub column: usize,
+    /// The token that could not be parsed, empty if the line ended early.
+    pub token: String,
+    /// What was expected instead, usually the name of a type.
+    pub expected: String,
+    /// The line of the input the token is on.
+    pub source_line: String,
+}
+
+impl ParseError {
+    /// Creates an error for `token`, which must be a slice of `input`, so its position can be determined.
+    pub fn new(input: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| offset + token.len() <= input.len())
+            .expect("token must be a slice of the input");
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        Self {
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            token: token.to_string(),
+            expected: expected.into(),
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+
+    /// Creates an error for a token that does not parse as `T`.
+    pub fn expected_type<T>(input: &str, token: &str) -> Self {
+        Self::new(input, token, short_type_name::<T>())
+    }
+
+    /// Moves an error from parsing `line` on its own to where `line` is in `input`, of which it must be a slice.
+    pub fn within(self, input: &str, line: &str) -> Self {
+        let start = Self::new(input, line, "");
+        if self.line > 1 {
+            return Self {
+                line: start.line + self.line - 1,
+                ..self
+            };
+        }
+        Self {
+            line: start.line,
+            column: start.column + self.column - 1,
+            source_line: start.source_line,
+            ..self
+        }
+    }
+
+    /// Renders the error with the offending line, underlining the token with carets.
+    pub fn snippet(&self) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let carets = "^".repeat(self.token.chars().count().max(1));
+
+        let mut snippet = String::new();
+        let _ = writeln!(snippet, "error: {}", self.message());
+        let _ = writeln!(
+            snippet,
+            "{gutter}--> line {}, column {}",
+            self.line, self.column
+        );
+        let _ = writeln!(snippet, "{gutter} |");
+        let _ = writeln!(snippet, "{number} | {}", self.source_line);
+        let _ = write!(
+            snippet,
+            "{gutter} | {}{carets}",
+            " ".repeat(self.column - 1)
+        );
+        snippet
+    }
+
+    fn message(&self) -> String {
+        if self.token.is_empty() {
+            format!("expected {}, found end of line", self.expected)
+        } else {
+            format!("expected {}, found `{}`", self.expected, self.token)
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line,
+            self.column,
+            self.message()
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The name of a type without module paths, e.g. `Vec<String>` instead of `alloc::vec::Vec<alloc::string::String>`.
+fn short_type_name<T>() -> String {
+    let name = type_name::<T>();
+    let mut short = String::new();
+    let mut start = 0;
+
+    for (i, c) in name.char_indices() {
+        if matches!(c, '<' | '>' | ',' | '(' | ')' | '[' | ']' | ';' | '&' | ' ') {
+            short.push_str(last_segment(&name[start..i]));
+            short.push(c);
+            start = i + 1;
+        }
+    }
+    short.push_str(last_segment(&name[start..]));
+    short
+}
+
+fn last_segment(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{ParseError, short_type_name};
+
+    #[test]
+    fn locates_tokens() {
+        let input = "1x2x3\n4x5x6y\n";
+        let token = &input[10..12];
+        let error = ParseError::expected_type::<u64>(input, token);
+
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.source_line, "4x5x6y");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected u64, found `6y`"
+        );
+    }
+
+    #[test]
+    fn renders_snippets() {
+        let input = "1x2x3\n4x5x6y\n";
+        let error = ParseError::expected_type::<u64>(input, &input[10..12]);
+
+        assert_eq!(
+            error.snippet(),
+            "error: expected u64, found `6y`\n \
+             --> line 2, column 5\n  \
+             |\n\
+             2 | 4x5x6y\n  \
+             |     ^^"
+        );
+    }
+
+    #[test]
+    fn reports_missing_tokens() {
+        let input = "1,2\n3\n";
+        let error = ParseError::new(input, &input[5..5], "`,`");
+
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected `,`, found end of line"
+        );
+        assert!(error.snippet().ends_with("2 | 3\n  |  ^"));
+    }
+
+    #[test]
+    fn relocates_errors_within_the_input() {
+        let input = "Dasher: 3\n  Comet: x\n";
+        let line = &input[12..20];
+        let error = ParseError::expected_type::<u64>(line, &line[7..]).within(input, line);
+
+        assert_eq!((error.line, error.column), (2, 10));
+        assert_eq!(error.source_line, "  Comet: x");
+        assert_eq!(
+            error,
+            ParseError::expected_type::<u64>(input, &input[19..20])
+        );
+    }
+
+    #[test]
+    fn shortens_type_names() {
+        assert_eq!(short_type_name::<i64>(), "i64");
+        assert_eq!(short_type_name::<Vec<String>>(), "Vec<String>");
+        assert_eq!(short_type_name::<(u8, String)>(), "(u8, String)");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
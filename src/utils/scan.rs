@@ -0,0 +1,383 @@
+/// Module that backs the [`scan!`](crate::scan) macro, which matches a line against a pattern of literals and `{}`
+/// placeholders. Patterns are matched literally, the text of a placeholder ends at the first occurrence of the
+/// literal that follows it.
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use crate::utils::ParseError;
+
+/// Matches `$line` against a pattern and parses each `{}` as the corresponding type, returning a tuple.
+/// Fields can be any type that implements `FromStr`, or `&str` to borrow from the line.
+/// Errors are a [`ParseError`] positioned in the line, the placeholder count is checked when compiling.
+///
+/// ```
+/// use advent_of_code::scan;
+///
+/// let line = "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.";
+/// let (name, speed, fly, rest) = scan!(
+///     "{} can fly {} km/s for {} seconds, but then must rest for {} seconds.",
+///     line => (&str, u64, u64, u64)
+/// )?;
+/// assert_eq!((name, speed, fly, rest), ("Comet", 14, 10, 127));
+/// # Ok::<(), advent_of_code::utils::ParseError>(())
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($pattern:literal, $line:expr => ($($ty:ty),+ $(,)?)) => {{
+        const _: () = assert!(
+            $crate::utils::scan::placeholders($pattern) == [$(stringify!($ty)),+].len(),
+            "scan! expects one type per placeholder of the pattern"
+        );
+        let line: &str = $line;
+        $crate::utils::scan::Scanner::new($pattern, line).and_then(|mut scanner| {
+            #[allow(unused_imports)]
+            use $crate::utils::scan::{ScanFromStr as _, ScanStr as _};
+            let fields = ($(
+                (&$crate::utils::scan::Field::<$ty>::new(line, scanner.next_field()?)).parse_field()?,
+            )+);
+            scanner.finish()?;
+            Ok(fields)
+        })
+    }};
+}
+
+/// Counts the `{}` placeholders of a pattern, which must be separated by literals.
+pub const fn placeholders(pattern: &str) -> usize {
+    let bytes = pattern.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+
+    while i + 1 < bytes.len() {
+        if bytes[i] == b'{' && bytes[i + 1] == b'}' {
+            assert!(
+                i + 3 >= bytes.len() || bytes[i + 2] != b'{' || bytes[i + 3] != b'}',
+                "placeholders of a pattern must be separated by a literal"
+            );
+            count += 1;
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+    count
+}
+
+/// Walks a line along a pattern, yielding the text of each placeholder.
+pub struct Scanner<'p, 'a> {
+    pattern: &'p str,
+    line: &'a str,
+    rest: &'a str,
+}
+
+impl<'p, 'a> Scanner<'p, 'a> {
+    /// Matches the literal the pattern starts with.
+    pub fn new(pattern: &'p str, line: &'a str) -> Result<Self, ParseError> {
+        let (literal, pattern) = split_literal(pattern);
+        match line.strip_prefix(literal) {
+            Some(rest) => Ok(Self {
+                pattern,
+                line,
+                rest,
+            }),
+            None => Err(mismatch(line, line, literal)),
+        }
+    }
+
+    /// Returns the text of the next placeholder and matches the literal after it.
+    pub fn next_field(&mut self) -> Result<&'a str, ParseError> {
+        let pattern = self
+            .pattern
+            .strip_prefix("{}")
+            .expect("more fields than placeholders");
+        let (literal, pattern) = split_literal(pattern);
+
+        let end = if literal.is_empty() {
+            self.rest.len()
+        } else {
+            self.rest
+                .find(literal)
+                .ok_or_else(|| mismatch(self.line, &self.rest[self.rest.len()..], literal))?
+        };
+
+        let field = &self.rest[..end];
+        self.rest = &self.rest[end + literal.len()..];
+        self.pattern = pattern;
+        Ok(field)
+    }
+
+    /// Checks that the whole line was matched.
+    pub fn finish(self) -> Result<(), ParseError> {
+        if self.rest.is_empty() {
+            Ok(())
+        } else {
+            Err(ParseError::new(self.line, self.rest, "end of line"))
+        }
+    }
+}
+
+/// Splits a pattern into the literal it starts with and the rest, starting at the next placeholder.
+fn split_literal(pattern: &str) -> (&str, &str) {
+    pattern.split_at(pattern.find("{}").unwrap_or(pattern.len()))
+}
+
+/// An error for `rest` not starting with `literal`, underlining as many characters as the literal has.
+fn mismatch(line: &str, rest: &str, literal: &str) -> ParseError {
+    let len = rest
+        .char_indices()
+        .nth(literal.chars().count())
+        .map_or(rest.len(), |(i, _)| i);
+    ParseError::new(line, &rest[..len], format!("`{literal}`"))
+}
+
+/// Parses the text of a placeholder, which must be a slice of `line`.
+pub fn parse_value<T: FromStr>(line: &str, field: &str) -> Result<T, ParseError> {
+    field
+        .parse()
+        .map_err(|_| ParseError::expected_type::<T>(line, field))
+}
+
+/// Splits a list like `cats: 7, trees: 3` into its keys and values, which are slices of `line`.
+pub fn key_values<'a>(
+    line: &'a str,
+    list: &'a str,
+    separator: &'a str,
+    assign: &'a str,
+) -> impl Iterator<Item = Result<(&'a str, &'a str), ParseError>> {
+    list.split(separator).map(move |pair| {
+        pair.split_once(assign)
+            .ok_or_else(|| mismatch(line, &pair[pair.len()..], assign))
+    })
+}
+
+/// An error for a list that lacks `key`, positioned at the end of the list.
+pub fn missing_key(line: &str, list: &str, key: &str) -> ParseError {
+    ParseError::new(line, &list[list.len()..], format!("`{key}`"))
+}
+
+/// The text of a placeholder, parsed by [`ScanStr`] for `&str` fields and by [`ScanFromStr`] otherwise.
+/// The macro calls `parse_field` on a reference, so method resolution prefers [`ScanStr`], which takes
+/// the field by value, before auto-referencing for [`ScanFromStr`].
+pub struct Field<'a, T> {
+    line: &'a str,
+    field: &'a str,
+    marker: PhantomData<T>,
+}
+
+impl<'a, T> Field<'a, T> {
+    pub fn new(line: &'a str, field: &'a str) -> Self {
+        Self {
+            line,
+            field,
+            marker: PhantomData,
+        }
+    }
+}
+
+pub trait ScanStr<'a> {
+    fn parse_field(&self) -> Result<&'a str, ParseError>;
+}
+
+impl<'a> ScanStr<'a> for Field<'a, &'a str> {
+    fn parse_field(&self) -> Result<&'a str, ParseError> {
+        Ok(self.field)
+    }
+}
+
+pub trait ScanFromStr<T> {
+    fn parse_field(&self) -> Result<T, ParseError>;
+}
+
+impl<T: FromStr> ScanFromStr<T> for &Field<'_, T> {
+    fn parse_field(&self) -> Result<T, ParseError> {
+        self.field
+            .parse()
+            .map_err(|_| ParseError::expected_type::<T>(self.line, self.field))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::placeholders;
+    use crate::utils::AocParse;
+
+    #[test]
+    fn scans_typed_fields() {
+        let line = "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.";
+        let reindeer = scan!(
+            "{} can fly {} km/s for {} seconds, but then must rest for {} seconds.",
+            line => (String, u64, u64, u64)
+        );
+        assert_eq!(reindeer, Ok(("Comet".to_string(), 14, 10, 127)));
+
+        let route = scan!("{} to {} = {}", "London to Dublin = 464" => (&str, &str, u32));
+        assert_eq!(route, Ok(("London", "Dublin", 464)));
+
+        assert_eq!(scan!("#{}", "#-12" => (i8)), Ok((-12,)));
+    }
+
+    #[test]
+    fn reports_positioned_errors() {
+        let error = scan!("{} = {}", "London = far" => (&str, u32)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 10: expected u32, found `far`"
+        );
+
+        let error =
+            scan!("{} to {} = {}", "London - Dublin = 464" => (&str, &str, u32)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 22: expected ` to `, found end of line"
+        );
+
+        let error = scan!("move {}", "mov 3" => (u8)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected `move `, found `mov 3`"
+        );
+
+        let error = scan!("{} apples.", "3 apples. 4 pears." => (u8)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 10: expected end of line, found ` 4 pears.`"
+        );
+    }
+
+    #[test]
+    fn derives_patterns() {
+        #[derive(Debug, PartialEq, AocParse)]
+        #[aoc(
+            pattern = "{name} would {gain} {units} happiness units by sitting next to {neighbor}."
+        )]
+        struct Preference {
+            name: String,
+            neighbor: String,
+            gain: String,
+            units: i64,
+        }
+
+        #[derive(Debug, PartialEq, AocParse)]
+        #[aoc(pattern = "{}x{}x{}")]
+        struct Present(u32, u32, u32);
+
+        #[derive(Debug, PartialEq, AocParse)]
+        #[aoc(pattern = "{_} to {_} = {distance}")]
+        struct Route {
+            distance: u32,
+        }
+
+        let line = "Alice would lose 57 happiness units by sitting next to Bob.";
+        assert_eq!(
+            line.parse(),
+            Ok(Preference {
+                name: "Alice".to_string(),
+                neighbor: "Bob".to_string(),
+                gain: "lose".to_string(),
+                units: 57,
+            })
+        );
+        assert_eq!("2x3x4".parse(), Ok(Present(2, 3, 4)));
+        assert_eq!(
+            "London to Dublin = 464".parse(),
+            Ok(Route { distance: 464 })
+        );
+
+        let error = "2x3".parse::<Present>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 4: expected `x`, found end of line"
+        );
+    }
+
+    #[test]
+    fn derives_enums() {
+        #[derive(Debug, PartialEq, AocParse)]
+        enum Instruction {
+            #[aoc(pattern = "turn on {}")]
+            TurnOn(u32),
+            #[aoc(pattern = "toggle {x},{y}")]
+            Toggle { x: u32, y: u32 },
+            #[aoc(pattern = "noop")]
+            Noop,
+            // variants are parsed by functions named after them, which must not clash.
+            #[aoc(pattern = "no-op")]
+            NoOp,
+        }
+
+        assert_eq!("turn on 3".parse(), Ok(Instruction::TurnOn(3)));
+        assert_eq!("toggle 4,5".parse(), Ok(Instruction::Toggle { x: 4, y: 5 }));
+        assert_eq!("noop".parse(), Ok(Instruction::Noop));
+        assert_eq!("no-op".parse(), Ok(Instruction::NoOp));
+
+        let error = "toggle 4,y".parse::<Instruction>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 10: expected u32, found `y`"
+        );
+    }
+
+    #[test]
+    fn derives_key_values() {
+        #[derive(Debug, PartialEq, AocParse)]
+        #[aoc(pattern = "Sue {id}: {..}")]
+        struct Aunt {
+            id: u32,
+            cats: Option<u8>,
+            #[aoc(key = "perfumes")]
+            perfume: Option<u8>,
+        }
+
+        #[derive(Debug, PartialEq, AocParse)]
+        #[aoc(key_value, separator = " ", assign = "=")]
+        struct Position {
+            x: i32,
+            y: i32,
+        }
+
+        assert_eq!(
+            "Sue 7: perfumes: 1, cats: 3".parse(),
+            Ok(Aunt {
+                id: 7,
+                cats: Some(3),
+                perfume: Some(1),
+            })
+        );
+        assert_eq!(
+            "Sue 8: cats: 0".parse(),
+            Ok(Aunt {
+                id: 8,
+                cats: Some(0),
+                perfume: None,
+            })
+        );
+        assert_eq!("y=-2 x=5".parse(), Ok(Position { x: 5, y: -2 }));
+
+        let error = "Sue 9: dogs: 2".parse::<Aunt>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 8: expected one of `cats`, `perfumes`, found `dogs`"
+        );
+
+        let error = "Sue 9: cats 2".parse::<Aunt>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 14: expected `: `, found end of line"
+        );
+
+        let error = "x=1".parse::<Position>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 4: expected `y`, found end of line"
+        );
+    }
+
+    #[test]
+    fn counts_placeholders() {
+        assert_eq!(placeholders("no fields"), 0);
+        assert_eq!(placeholders("{} to {} = {}"), 3);
+    }
+}
+
+/* -------------------------------------------------------------------------- */